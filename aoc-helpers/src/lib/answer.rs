@@ -0,0 +1,124 @@
+use std::fmt;
+
+// What a solution produces for one part of a puzzle.  Art is for puzzles whose
+// answer is drawn as letters in a grid (e.g. 2021 day 13); it has to be read by
+// a person before it can be submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    Art(String),
+    Unimplemented,
+}
+
+impl Answer {
+    pub fn is_implemented(&self) -> bool {
+        *self != Answer::Unimplemented
+    }
+
+    // The text to send to the site, if this is something that can be sent as is.
+    pub fn submission(&self) -> Option<String> {
+        match self {
+            Answer::Number(n) => Some(n.to_string()),
+            Answer::Text(s) if !s.is_empty() => Some(s.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Art(s) => write!(f, "{}", s.trim_end_matches('\n')),
+            Answer::Unimplemented => write!(f, "unimplemented"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Answer {
+        match i128::try_from(n) {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Text(n.to_string()),
+        }
+    }
+}
+
+// Multi-line strings are taken to be drawings.
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        if s.trim_end_matches('\n').contains('\n') {
+            Answer::Art(s)
+        } else {
+            Answer::Text(s)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::from(s.to_string())
+    }
+}
+
+// Lets tests compare against the expected answer as it appears on the site.
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        let shown = self.to_string();
+        self.is_implemented() && shown == other
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+#[cfg(test)]
+mod answer_tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Answer::from(42usize), Answer::Number(42));
+        assert_eq!(Answer::from(-7i64), Answer::Number(-7));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+        assert_eq!(Answer::from("abc"), Answer::Text("abc".to_string()));
+        assert_eq!(
+            Answer::from("#.\n.#\n"),
+            Answer::Art("#.\n.#\n".to_string())
+        );
+    }
+
+    #[test]
+    fn compares_with_str() {
+        assert_eq!(Answer::from(102u32), "102");
+        assert_eq!(Answer::from("#.\n.#\n"), "#.\n.#");
+        assert_ne!(Answer::Unimplemented, "unimplemented");
+    }
+
+    #[test]
+    fn submissions() {
+        assert_eq!(Answer::from(3i32).submission(), Some("3".to_string()));
+        assert_eq!(Answer::from("").submission(), None);
+        assert_eq!(Answer::from("#.\n.#").submission(), None);
+        assert_eq!(Answer::Unimplemented.submission(), None);
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Known-good answers for a single year, stored next to the cached inputs as
+// tab separated `day part answer` lines in `<cache dir>/<year>/answers.tsv`.
+// Newlines and backslashes in answers are escaped so each answer stays on one
+// line.
+pub struct Answers {
+    path: PathBuf,
+    answers: BTreeMap<(u32, u32), String>,
+}
+
+impl Answers {
+    pub fn path_for(cache_dir: &Path, year: u32) -> PathBuf {
+        cache_dir.join(year.to_string()).join("answers.tsv")
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Answers> {
+        let path = path.as_ref().to_path_buf();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        let mut answers = BTreeMap::new();
+        for (i, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (key, answer) = parse_line(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}:{}: malformed answer entry", path.display(), i + 1),
+                )
+            })?;
+            answers.insert(key, answer);
+        }
+        Ok(Answers { path, answers })
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|a| a.as_str())
+    }
+
+    // Returns the previously recorded answer, if it was different.
+    pub fn set(&mut self, day: u32, part: u32, answer: &str) -> Option<String> {
+        match self.answers.insert((day, part), answer.to_string()) {
+            Some(old) if old != answer => Some(old),
+            _ => None,
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut contents = String::new();
+        for ((day, part), answer) in &self.answers {
+            contents += &format!("{}\t{}\t{}\n", day, part, escape(answer));
+        }
+        fs::write(&self.path, contents)
+    }
+}
+
+fn parse_line(line: &str) -> Option<((u32, u32), String)> {
+    let mut fields = line.splitn(3, '\t');
+    let day = fields.next()?.parse().ok()?;
+    let part = fields.next()?.parse().ok()?;
+    let answer = unescape(fields.next()?)?;
+    Some(((day, part), answer))
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(field: &str) -> Option<String> {
+    let mut answer = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            answer.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => answer.push('\n'),
+            '\\' => answer.push('\\'),
+            _ => return None,
+        }
+    }
+    Some(answer)
+}
+
+#[cfg(test)]
+mod answers_tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn round_trip() {
+        let dir = env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        let path = Answers::path_for(&dir, 2021);
+        let mut answers = Answers::load(&path).unwrap();
+        assert_eq!(answers.get(13, 2), None);
+
+        assert_eq!(answers.set(1, 1, "1292"), None);
+        assert_eq!(answers.set(13, 2, "#..#\n.##.\\"), None);
+        assert_eq!(answers.set(1, 1, "1292"), None);
+        answers.save().unwrap();
+
+        let mut reloaded = Answers::load(&path).unwrap();
+        assert_eq!(reloaded.get(1, 1), Some("1292"));
+        assert_eq!(reloaded.get(13, 2), Some("#..#\n.##.\\"));
+        assert_eq!(reloaded.set(1, 1, "1293"), Some("1292".to_string()));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
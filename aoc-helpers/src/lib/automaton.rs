@@ -0,0 +1,306 @@
+use crate::coord::{Coord, Direction8};
+use crate::grid::Grid;
+use crate::sparse::{self, Point, SparseGrid};
+use std::collections::HashMap;
+use std::mem;
+
+// Hex cells in axial coordinates, as [row, col] (or Coord::new(row, col)):
+// east and west are along a row, and the other four neighbours are
+// north-west (-1, 0), north-east (-1, 1), south-west (1, -1) and south-east
+// (1, 0).
+pub const HEX_DELTAS: [(i64, i64); 6] = [(0, 1), (0, -1), (-1, 0), (-1, 1), (1, -1), (1, 0)];
+
+// Which cells count as a cell's neighbours.
+pub enum Neighborhood<T> {
+    // The 8 surrounding cells (3^N - 1 in N dimensions).
+    Moore,
+    // The 4 orthogonal cells (2N in N dimensions).
+    VonNeumann,
+    // The 6 cells around a hex; see HEX_DELTAS.
+    Hex,
+    // The first cell in each of the 8 directions that isn't see-through
+    // according to the function.  Only makes sense on a bounded grid.
+    LineOfSight(fn(&T) -> bool),
+}
+
+// Derived Clone/Copy would needlessly require T: Copy.
+impl<T> Clone for Neighborhood<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Neighborhood<T> {}
+
+impl<T> Neighborhood<T> {
+    fn collect<'a>(&self, grid: &'a Grid<T>, coord: Coord, out: &mut Vec<&'a T>) {
+        match self {
+            Neighborhood::Moore => out.extend(grid.neighbors8(coord).map(|c| &grid[c])),
+            Neighborhood::VonNeumann => out.extend(grid.neighbors4(coord).map(|c| &grid[c])),
+            Neighborhood::Hex => out.extend(
+                HEX_DELTAS
+                    .iter()
+                    .filter_map(|&(r, c)| grid.get(coord + Coord::new(r, c))),
+            ),
+            Neighborhood::LineOfSight(see_through) => {
+                for dir in Direction8::ALL {
+                    let mut pos = coord + dir;
+                    while let Some(cell) = grid.get(pos) {
+                        if !see_through(cell) {
+                            out.push(cell);
+                            break;
+                        }
+                        pos += dir;
+                    }
+                }
+            }
+        }
+    }
+
+    fn points<const N: usize>(&self, point: Point<N>) -> Vec<Point<N>> {
+        match self {
+            Neighborhood::Moore => sparse::neighbors(point).collect(),
+            Neighborhood::VonNeumann => sparse::orthogonal_neighbors(point).collect(),
+            Neighborhood::Hex => {
+                assert_eq!(N, 2, "hex neighbourhoods are two dimensional");
+                HEX_DELTAS
+                    .iter()
+                    .map(|&(r, c)| {
+                        let mut p = point;
+                        p[0] += r;
+                        p[1] += c;
+                        p
+                    })
+                    .collect()
+            }
+            Neighborhood::LineOfSight(_) => panic!("line of sight needs a bounded grid"),
+        }
+    }
+}
+
+// A cellular automaton on a fixed-size grid.  Each generation, `rule` is given
+// every cell along with its neighbours and returns what the cell becomes; all
+// cells update at once.
+pub struct Automaton<T, R> {
+    current: Grid<T>,
+    next: Grid<T>,
+    neighborhood: Neighborhood<T>,
+    rule: R,
+    generation: usize,
+}
+
+impl<T, R> Automaton<T, R>
+where
+    T: Clone + PartialEq,
+    R: Fn(&T, &[&T]) -> T,
+{
+    pub fn new(grid: Grid<T>, neighborhood: Neighborhood<T>, rule: R) -> Automaton<T, R> {
+        Automaton {
+            next: grid.clone(),
+            current: grid,
+            neighborhood,
+            rule,
+            generation: 0,
+        }
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.current
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.current
+    }
+
+    // How many generations have been run so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    // Runs one generation and returns how many cells changed.
+    pub fn step(&mut self) -> usize {
+        let mut changed = 0;
+        let mut neighbors: Vec<&T> = Vec::with_capacity(8);
+        for (coord, cell) in self.current.iter() {
+            neighbors.clear();
+            self.neighborhood
+                .collect(&self.current, coord, &mut neighbors);
+            let new = (self.rule)(cell, &neighbors);
+            if new != *cell {
+                changed += 1;
+            }
+            self.next[coord] = new;
+        }
+        mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+        changed
+    }
+
+    // Runs `generations` generations and returns the total number of changes.
+    pub fn run(&mut self, generations: usize) -> usize {
+        (0..generations).map(|_| self.step()).sum()
+    }
+
+    // Steps until a generation changes nothing.  Returns the number of
+    // generations that did change something.
+    pub fn run_until_stable(&mut self) -> usize {
+        let mut generations = 0;
+        while self.step() > 0 {
+            generations += 1;
+        }
+        generations
+    }
+}
+
+// A two-state automaton on an unbounded grid of any dimension, storing only the
+// live cells.  `rule` gets whether a cell is alive and how many live neighbours
+// it has, and says whether it's alive next generation.
+pub struct SparseAutomaton<R, const N: usize> {
+    live: SparseGrid<(), N>,
+    neighborhood: Neighborhood<()>,
+    rule: R,
+    generation: usize,
+}
+
+impl<R, const N: usize> SparseAutomaton<R, N>
+where
+    R: Fn(bool, usize) -> bool,
+{
+    // Line of sight isn't supported: with no edges there's nothing to stop it.
+    pub fn new(
+        live: SparseGrid<(), N>,
+        neighborhood: Neighborhood<()>,
+        rule: R,
+    ) -> SparseAutomaton<R, N> {
+        SparseAutomaton {
+            live,
+            neighborhood,
+            rule,
+            generation: 0,
+        }
+    }
+
+    pub fn live(&self) -> &SparseGrid<(), N> {
+        &self.live
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    // Runs one generation and returns how many cells changed.
+    pub fn step(&mut self) -> usize {
+        // Only live cells and their neighbours can be alive next time.
+        let mut counts: HashMap<Point<N>, usize> = HashMap::new();
+        for point in self.live.points() {
+            counts.entry(*point).or_insert(0);
+            for n in self.neighborhood.points(*point) {
+                *counts.entry(n).or_insert(0) += 1;
+            }
+        }
+        let mut next = SparseGrid::new();
+        let mut changed = 0;
+        for (point, count) in counts {
+            let alive = self.live.contains(&point);
+            let now = (self.rule)(alive, count);
+            if now {
+                next.insert(point, ());
+            }
+            if now != alive {
+                changed += 1;
+            }
+        }
+        self.live = next;
+        self.generation += 1;
+        changed
+    }
+
+    pub fn run(&mut self, generations: usize) -> usize {
+        (0..generations).map(|_| self.step()).sum()
+    }
+
+    pub fn run_until_stable(&mut self) -> usize {
+        let mut generations = 0;
+        while self.step() > 0 {
+            generations += 1;
+        }
+        generations
+    }
+}
+
+#[cfg(test)]
+mod automaton_tests {
+    use super::*;
+
+    fn life(alive: bool, neighbors: usize) -> bool {
+        neighbors == 3 || (alive && neighbors == 2)
+    }
+
+    #[test]
+    fn blinker() {
+        let grid = Grid::parse(".....\n..#..\n..#..\n..#..\n.....\n", |c| c == '#');
+        let rule = |cell: &bool, neighbors: &[&bool]| {
+            life(*cell, neighbors.iter().filter(|n| ***n).count())
+        };
+        let mut automaton = Automaton::new(grid.clone(), Neighborhood::Moore, rule);
+        assert_eq!(automaton.step(), 4);
+        assert_eq!(automaton.grid(), &grid.rotate_right());
+        assert_eq!(automaton.run(3), 12);
+        assert_eq!(automaton.generation(), 4);
+        assert_eq!(automaton.into_grid(), grid);
+    }
+
+    #[test]
+    fn settles() {
+        // Each cell takes the largest value it can see, so 9 floods the grid.
+        let grid = Grid::parse("1111\n1191\n1111\n", |c| c.to_digit(10).unwrap());
+        let rule = |cell: &u32, neighbors: &[&u32]| {
+            neighbors.iter().map(|n| **n).max().unwrap_or(0).max(*cell)
+        };
+        let mut automaton = Automaton::new(grid.clone(), Neighborhood::VonNeumann, rule);
+        assert_eq!(automaton.run_until_stable(), 3);
+        assert!(automaton.grid().iter().all(|(_, v)| *v == 9));
+
+        let mut automaton = Automaton::new(grid, Neighborhood::Moore, rule);
+        assert_eq!(automaton.run_until_stable(), 2);
+    }
+
+    #[test]
+    fn line_of_sight() {
+        let grid = Grid::parse("#..#\n....\n#..#\n", |c| c);
+        let mut seen = Vec::new();
+        Neighborhood::LineOfSight(|c: &char| *c == '.').collect(&grid, Coord::new(0, 0), &mut seen);
+        assert_eq!(seen.len(), 2);
+
+        let mut hex = Vec::new();
+        Neighborhood::Hex.collect(&grid, Coord::new(1, 1), &mut hex);
+        assert_eq!(hex.len(), 6);
+    }
+
+    #[test]
+    fn sparse_life() {
+        let glider: SparseGrid<(), 2> = [[0, 1], [1, 2], [2, 0], [2, 1], [2, 2]]
+            .into_iter()
+            .map(|p| (p, ()))
+            .collect();
+        let mut automaton = SparseAutomaton::new(glider.clone(), Neighborhood::Moore, life);
+        automaton.run(4);
+        // After four generations a glider has moved one down and one right.
+        let moved: SparseGrid<(), 2> = glider
+            .points()
+            .map(|p| ([p[0] + 1, p[1] + 1], ()))
+            .collect();
+        assert_eq!(automaton.live(), &moved);
+
+        let mut still = SparseAutomaton::new(
+            [[0, 0], [0, 1], [1, 0], [1, 1]]
+                .into_iter()
+                .map(|p| (p, ()))
+                .collect(),
+            Neighborhood::Moore,
+            life,
+        );
+        assert_eq!(still.run_until_stable(), 0);
+        assert_eq!(still.generation(), 1);
+    }
+}
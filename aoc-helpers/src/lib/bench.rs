@@ -0,0 +1,107 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+// Runs `f` once, returning its result along with how long it took.
+pub fn time<R, F: FnOnce() -> R>(f: F) -> (R, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+        let total: Duration = sorted.iter().sum();
+        Some(Stats {
+            runs: n,
+            min: sorted[0],
+            median,
+            mean: total / n as u32,
+            max: sorted[n - 1],
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} runs: min {}, median {}, mean {}, max {}",
+            self.runs,
+            format_duration(self.min),
+            format_duration(self.median),
+            format_duration(self.mean),
+            format_duration(self.max)
+        )
+    }
+}
+
+// Runs `f` `runs` times and summarizes how long each call took.
+pub fn bench<R, F: FnMut() -> R>(runs: usize, mut f: F) -> Option<Stats> {
+    let samples: Vec<Duration> = (0..runs).map(|_| time(&mut f).1).collect();
+    Stats::from_samples(&samples)
+}
+
+// Picks a unit that keeps the number readable, e.g. "512ns", "3.21ms", "1.50s".
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", d.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod bench_tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let ms = Duration::from_millis;
+        assert_eq!(Stats::from_samples(&[]), None);
+        assert_eq!(
+            Stats::from_samples(&[ms(5), ms(1), ms(3)]),
+            Some(Stats {
+                runs: 3,
+                min: ms(1),
+                median: ms(3),
+                mean: ms(3),
+                max: ms(5)
+            })
+        );
+        let even = Stats::from_samples(&[ms(4), ms(1), ms(2), ms(9)]).unwrap();
+        assert_eq!(even.median, ms(3));
+        assert_eq!(even.mean, ms(4));
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512ns");
+        assert_eq!(format_duration(Duration::from_micros(3210)), "3.21ms");
+        assert_eq!(format_duration(Duration::from_millis(1500)), "1.50s");
+    }
+}
@@ -0,0 +1,291 @@
+use crate::session::{session_file, SessionError};
+use isahc::{prelude::*, send, Request};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// Talks to the AoC site, keeping a copy of every downloaded input on disk so
+// repeated runs (and offline runs) don't need to go back to the network.
+pub struct AocClient {
+    base_url: String,
+    cache_dir: PathBuf,
+    session: Option<String>,
+}
+
+impl Default for AocClient {
+    fn default() -> Self {
+        AocClient::new()
+    }
+}
+
+impl AocClient {
+    // Builds a client using the defaults, which can be overridden with the
+    // AOC_BASE_URL and AOC_INPUT_DIR environment variables.
+    pub fn new() -> AocClient {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let cache_dir = match env::var("AOC_INPUT_DIR") {
+            Ok(dir) => PathBuf::from(dir),
+            Err(_) => default_cache_dir(),
+        };
+        AocClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            cache_dir,
+            session: None,
+        }
+    }
+
+    pub fn with_session(mut self, session: &str) -> AocClient {
+        self.session = Some(session.trim().to_string());
+        self
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> AocClient {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_cache_dir<P: AsRef<Path>>(mut self, cache_dir: P) -> AocClient {
+        self.cache_dir = cache_dir.as_ref().to_path_buf();
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    pub fn input_path(&self, year: u32, day: u32) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day{}.txt", day))
+    }
+
+    // Returns the puzzle input, reading it from the cache if we've already
+    // downloaded it.  Passing `refresh` skips the cache and overwrites it with
+    // a freshly fetched copy.
+    pub fn get_input(&self, year: u32, day: u32, refresh: bool) -> io::Result<String> {
+        let path = self.input_path(year, day);
+        if !refresh {
+            match fs::read_to_string(&path) {
+                Ok(input) => return Ok(input),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e),
+            }
+        }
+        let input = self.fetch_input(year, day)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, &input)?;
+        Ok(input)
+    }
+
+    // Only needed once we actually have to talk to the site, so that cached
+    // inputs can still be used without a session token.
+    fn session(&self) -> io::Result<&str> {
+        self.session.as_deref().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                SessionError::Missing {
+                    config_file: session_file(),
+                },
+            )
+        })
+    }
+
+    pub fn fetch_input(&self, year: u32, day: u32) -> io::Result<String> {
+        let request = Request::get(format!("{}/{}/day/{}/input", self.base_url, year, day))
+            .header("cookie", format!("session={}", self.session()?))
+            .body(())
+            .map_err(io::Error::other)?;
+        let mut response = send(request)?;
+        let body = response.text()?;
+        if !response.status().is_success() {
+            return Err(io::Error::other(format!(
+                "fetching input for {} day {} failed with status {}: {}",
+                year,
+                day,
+                response.status(),
+                body.trim()
+            )));
+        }
+        Ok(body)
+    }
+
+    pub fn post_answer(&self, year: u32, day: u32, part: u32, answer: &str) -> io::Result<String> {
+        let request = Request::post(format!("{}/{}/day/{}/answer", self.base_url, year, day))
+            .header("cookie", format!("session={}", self.session()?))
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(format!("level={}&answer={}", part, form_encode(answer)))
+            .map_err(io::Error::other)?;
+        let mut response = send(request)?;
+        response.text()
+    }
+}
+
+// Escapes a value for an application/x-www-form-urlencoded body, so answers
+// with spaces, '&', '=' and the like arrive intact.
+fn form_encode(value: &str) -> String {
+    let mut encoded = String::new();
+    for b in value.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'*' | b'-' | b'.' | b'_' => {
+                encoded.push(b as char)
+            }
+            b' ' => encoded.push('+'),
+            _ => encoded.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    encoded
+}
+
+// `inputs/` at the root of the workspace, i.e. next to aoc-helpers.
+pub fn default_cache_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join("inputs")
+}
+
+#[cfg(test)]
+mod client_tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    type Received = Arc<Mutex<Vec<String>>>;
+
+    // Minimal stand-in for adventofcode.com: answers every request with the
+    // given body, counts how many requests it has seen and keeps their bodies.
+    fn serve(body: &'static str, status: &'static str) -> (String, Arc<AtomicUsize>, Received) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        let received: Received = Arc::new(Mutex::new(Vec::new()));
+        let bodies = received.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = match stream {
+                    Ok(s) => s,
+                    Err(_) => break,
+                };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut content_length = 0usize;
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                        break;
+                    }
+                    let lower = line.to_ascii_lowercase();
+                    if let Some(len) = lower.strip_prefix("content-length:") {
+                        content_length = len.trim().parse().unwrap();
+                    }
+                }
+                let mut request_body = vec![0u8; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+                bodies
+                    .lock()
+                    .unwrap()
+                    .push(String::from_utf8(request_body).unwrap());
+                counter.fetch_add(1, Ordering::SeqCst);
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        (url, hits, received)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-helpers-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetches_then_caches() {
+        let (url, hits, _) = serve("1\n2\n3\n", "200 OK");
+        let dir = temp_dir("cache");
+        let client = AocClient::new()
+            .with_session("abc")
+            .with_base_url(&url)
+            .with_cache_dir(&dir);
+
+        assert_eq!(client.get_input(2020, 1, false).unwrap(), "1\n2\n3\n");
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            fs::read_to_string(dir.join("2020").join("day1.txt")).unwrap(),
+            "1\n2\n3\n"
+        );
+
+        // Served from disk the second time around.
+        assert_eq!(client.get_input(2020, 1, false).unwrap(), "1\n2\n3\n");
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+        // Unless we ask for a refresh.
+        assert_eq!(client.get_input(2020, 1, true).unwrap(), "1\n2\n3\n");
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn failed_fetch_is_not_cached() {
+        let (url, _, _) = serve("Please log in", "400 Bad Request");
+        let dir = temp_dir("failed");
+        let client = AocClient::new()
+            .with_session("abc")
+            .with_base_url(&url)
+            .with_cache_dir(&dir);
+
+        assert!(client.get_input(2020, 2, false).is_err());
+        assert!(!client.input_path(2020, 2).exists());
+    }
+
+    #[test]
+    fn cache_hit_needs_no_session() {
+        let dir = temp_dir("nosession");
+        let client = AocClient::new()
+            .with_base_url("http://127.0.0.1:9")
+            .with_cache_dir(&dir);
+        let err = client.get_input(2020, 3, false).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+
+        fs::create_dir_all(dir.join("2020")).unwrap();
+        fs::write(client.input_path(2020, 3), "..#\n").unwrap();
+        assert_eq!(client.get_input(2020, 3, false).unwrap(), "..#\n");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn answers_are_form_encoded() {
+        let (url, _, received) = serve("That's the right answer!", "200 OK");
+        let client = AocClient::new().with_session("abc").with_base_url(&url);
+
+        client.post_answer(2020, 4, 2, "a b&c=d+e%f").unwrap();
+        client.post_answer(2020, 4, 1, "12345").unwrap();
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![
+                "level=2&answer=a+b%26c%3Dd%2Be%25f".to_string(),
+                "level=1&answer=12345".to_string(),
+            ]
+        );
+    }
+}
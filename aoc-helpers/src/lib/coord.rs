@@ -0,0 +1,339 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+// A position on a grid.  Signed so that stepping off an edge gives a
+// coordinate that is simply out of bounds rather than an underflow.  Rows grow
+// downwards, so north is towards row 0.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coord {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Coord {
+    pub const ORIGIN: Coord = Coord::new(0, 0);
+
+    pub const fn new(row: i64, col: i64) -> Coord {
+        Coord { row, col }
+    }
+
+    pub fn step(self, dir: Direction) -> Coord {
+        self + dir
+    }
+
+    pub fn manhattan(self, other: Coord) -> i64 {
+        (self.row - other.row).abs() + (self.col - other.col).abs()
+    }
+
+    // Quarter turns about the origin.
+    pub fn rotate_left(self) -> Coord {
+        Coord::new(-self.col, self.row)
+    }
+
+    pub fn rotate_right(self) -> Coord {
+        Coord::new(self.col, -self.row)
+    }
+}
+
+impl From<(usize, usize)> for Coord {
+    fn from((row, col): (usize, usize)) -> Coord {
+        Coord::new(row as i64, col as i64)
+    }
+}
+
+impl fmt::Display for Coord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+impl Add for Coord {
+    type Output = Coord;
+
+    fn add(self, other: Coord) -> Coord {
+        Coord::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl Sub for Coord {
+    type Output = Coord;
+
+    fn sub(self, other: Coord) -> Coord {
+        Coord::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl AddAssign for Coord {
+    fn add_assign(&mut self, other: Coord) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Coord {
+    fn sub_assign(&mut self, other: Coord) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Coord {
+    type Output = Coord;
+
+    fn mul(self, n: i64) -> Coord {
+        Coord::new(self.row * n, self.col * n)
+    }
+}
+
+impl Neg for Coord {
+    type Output = Coord;
+
+    fn neg(self) -> Coord {
+        Coord::new(-self.row, -self.col)
+    }
+}
+
+// The four ways to move orthogonally, listed clockwise from north.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn delta(self) -> Coord {
+        Direction8::from(self).delta()
+    }
+
+    // Turns clockwise by `quarters` quarter turns (negative turns
+    // anticlockwise).
+    pub fn rotate(self, quarters: i32) -> Direction {
+        Direction::ALL[(self as i32 + quarters).rem_euclid(4) as usize]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.rotate(-1)
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.rotate(1)
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.rotate(2)
+    }
+
+    // Accepts compass letters (NSEW), UDLR and the arrows ^v<>.
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            'N' | 'n' | 'U' | 'u' | '^' => Some(Direction::North),
+            'E' | 'e' | 'R' | 'r' | '>' => Some(Direction::East),
+            'S' | 's' | 'D' | 'd' | 'v' => Some(Direction::South),
+            'W' | 'w' | 'L' | 'l' | '<' => Some(Direction::West),
+            _ => None,
+        }
+    }
+}
+
+// Directions including the diagonals, listed clockwise from north.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    pub fn delta(self) -> Coord {
+        match self {
+            Direction8::North => Coord::new(-1, 0),
+            Direction8::NorthEast => Coord::new(-1, 1),
+            Direction8::East => Coord::new(0, 1),
+            Direction8::SouthEast => Coord::new(1, 1),
+            Direction8::South => Coord::new(1, 0),
+            Direction8::SouthWest => Coord::new(1, -1),
+            Direction8::West => Coord::new(0, -1),
+            Direction8::NorthWest => Coord::new(-1, -1),
+        }
+    }
+
+    // Turns clockwise by `eighths` eighths of a full turn (negative turns
+    // anticlockwise).
+    pub fn rotate(self, eighths: i32) -> Direction8 {
+        Direction8::ALL[(self as i32 + eighths).rem_euclid(8) as usize]
+    }
+
+    pub fn turn_left(self) -> Direction8 {
+        self.rotate(-2)
+    }
+
+    pub fn turn_right(self) -> Direction8 {
+        self.rotate(2)
+    }
+
+    pub fn reverse(self) -> Direction8 {
+        self.rotate(4)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Direction8 {
+        Direction8::ALL[dir as usize * 2]
+    }
+}
+
+impl Add<Direction> for Coord {
+    type Output = Coord;
+
+    fn add(self, dir: Direction) -> Coord {
+        self + dir.delta()
+    }
+}
+
+impl Add<Direction8> for Coord {
+    type Output = Coord;
+
+    fn add(self, dir: Direction8) -> Coord {
+        self + dir.delta()
+    }
+}
+
+impl AddAssign<Direction> for Coord {
+    fn add_assign(&mut self, dir: Direction) {
+        *self = *self + dir;
+    }
+}
+
+impl AddAssign<Direction8> for Coord {
+    fn add_assign(&mut self, dir: Direction8) {
+        *self = *self + dir;
+    }
+}
+
+// A single character, as for `Direction::from_char`.
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Direction, String> {
+        let mut chars = s.chars();
+        match (chars.next().and_then(Direction::from_char), chars.next()) {
+            (Some(dir), None) => Ok(dir),
+            _ => Err(format!("'{}' isn't a direction", s)),
+        }
+    }
+}
+
+// As for `Direction`, plus the diagonals as "NE", "SE", "SW" and "NW".
+impl FromStr for Direction8 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Direction8, String> {
+        if let Ok(dir) = s.parse::<Direction>() {
+            return Ok(dir.into());
+        }
+        match s.to_ascii_uppercase().as_str() {
+            "NE" => Ok(Direction8::NorthEast),
+            "SE" => Ok(Direction8::SouthEast),
+            "SW" => Ok(Direction8::SouthWest),
+            "NW" => Ok(Direction8::NorthWest),
+            _ => Err(format!("'{}' isn't a direction", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod coord_tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Coord::new(2, -3);
+        let b = Coord::new(-1, 5);
+        assert_eq!(a + b, Coord::new(1, 2));
+        assert_eq!(a - b, Coord::new(3, -8));
+        assert_eq!(a * 3, Coord::new(6, -9));
+        assert_eq!(-a, Coord::new(-2, 3));
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(b.manhattan(Coord::ORIGIN), 6);
+    }
+
+    #[test]
+    fn rotation() {
+        let north = Direction::North.delta();
+        assert_eq!(north.rotate_right(), Direction::East.delta());
+        assert_eq!(north.rotate_left(), Direction::West.delta());
+        let waypoint = Coord::new(-4, 10);
+        assert_eq!(waypoint.rotate_right().rotate_left(), waypoint);
+        assert_eq!(waypoint.rotate_right().rotate_right(), -waypoint);
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::East.rotate(-3), Direction::South);
+        assert_eq!(Direction8::SouthWest.reverse(), Direction8::NorthEast);
+        assert_eq!(Direction8::NorthWest.rotate(1), Direction8::North);
+        assert_eq!(Direction8::North.rotate(-1), Direction8::NorthWest);
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_right().delta(), dir.delta().rotate_right());
+            assert_eq!(
+                Direction8::from(dir.reverse()),
+                Direction8::from(dir).reverse()
+            );
+        }
+        for dir in Direction8::ALL {
+            assert_eq!(dir.delta() + dir.reverse().delta(), Coord::ORIGIN);
+            assert_eq!(dir.turn_right().delta(), dir.delta().rotate_right());
+        }
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(Direction::from_char('U'), Some(Direction::North));
+        assert_eq!(Direction::from_char('>'), Some(Direction::East));
+        assert_eq!(Direction::from_char('v'), Some(Direction::South));
+        assert_eq!(Direction::from_char('W'), Some(Direction::West));
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!("L".parse(), Ok(Direction::West));
+        assert!("NE".parse::<Direction>().is_err());
+        assert_eq!("NE".parse(), Ok(Direction8::NorthEast));
+        assert_eq!("D".parse(), Ok(Direction8::South));
+        assert!("NS".parse::<Direction8>().is_err());
+    }
+
+    #[test]
+    fn stepping() {
+        let mut pos = Coord::ORIGIN;
+        pos += Direction::South;
+        let mut pos = pos + Direction::East;
+        assert_eq!(pos, Coord::new(1, 1));
+        pos += Direction8::NorthWest;
+        assert_eq!(pos, Coord::ORIGIN);
+        assert_eq!(pos.step(Direction::North), Coord::new(-1, 0));
+    }
+}
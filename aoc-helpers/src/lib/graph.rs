@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+// A graph whose nodes are named by strings.  Each name is interned to a
+// compact id (0, 1, ... in the order they're first seen), so solutions can
+// work with plain indices and only go back to names when they need them.
+// Edges carry a weight of any type; () will do when there's nothing to say.
+#[derive(Clone, Debug)]
+pub struct Graph<W> {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    // Each node's outgoing and incoming edges, in the order they were added.
+    edges: Vec<Vec<(usize, W)>>,
+    reverse: Vec<Vec<(usize, W)>>,
+}
+
+// Derived Default would needlessly require W: Default.
+impl<W> Default for Graph<W> {
+    fn default() -> Self {
+        Graph {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            edges: Vec::new(),
+            reverse: Vec::new(),
+        }
+    }
+}
+
+impl<W> Graph<W> {
+    pub fn new() -> Graph<W> {
+        Graph::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    // The id for `name`, adding it as a new node if it hasn't been seen.
+    pub fn intern(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.edges.push(Vec::new());
+        self.reverse.push(Vec::new());
+        id
+    }
+
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    // Every node's id, which is also the order `names` gives them in.
+    pub fn nodes(&self) -> Range<usize> {
+        0..self.len()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(|name| name.as_str())
+    }
+
+    // Adds an edge from `from` to `to`, interning either name if it's new.
+    // Returns the two ids.
+    pub fn add_edge(&mut self, from: &str, to: &str, weight: W) -> (usize, usize)
+    where
+        W: Clone,
+    {
+        let (from, to) = (self.intern(from), self.intern(to));
+        self.edges[from].push((to, weight.clone()));
+        self.reverse[to].push((from, weight));
+        (from, to)
+    }
+
+    // Adds edges both ways between `a` and `b`, so each shows up among the
+    // other's neighbors.
+    pub fn add_undirected(&mut self, a: &str, b: &str, weight: W) -> (usize, usize)
+    where
+        W: Clone,
+    {
+        let ids = self.add_edge(a, b, weight.clone());
+        if ids.0 != ids.1 {
+            self.add_edge(b, a, weight);
+        }
+        ids
+    }
+
+    // The nodes `id` has edges to, with their weights.
+    pub fn neighbors(&self, id: usize) -> impl Iterator<Item = (usize, &W)> {
+        self.edges[id].iter().map(|(to, weight)| (*to, weight))
+    }
+
+    // The nodes with edges to `id`, with their weights.
+    pub fn incoming(&self, id: usize) -> impl Iterator<Item = (usize, &W)> {
+        self.reverse[id]
+            .iter()
+            .map(|(from, weight)| (*from, weight))
+    }
+
+    // The weight of the (first) edge from `from` to `to`, if there is one.
+    pub fn weight(&self, from: usize, to: usize) -> Option<&W> {
+        self.neighbors(from)
+            .find(|(next, _)| *next == to)
+            .map(|(_, weight)| weight)
+    }
+}
+
+#[cfg(test)]
+mod graph_tests {
+    use super::*;
+
+    #[test]
+    fn interning() {
+        let mut graph: Graph<()> = Graph::new();
+        assert!(graph.is_empty());
+        assert_eq!(graph.intern("b"), 0);
+        assert_eq!(graph.intern("a"), 1);
+        assert_eq!(graph.intern("b"), 0);
+        assert_eq!(graph.len(), 2);
+        assert_eq!(graph.id("a"), Some(1));
+        assert_eq!(graph.id("c"), None);
+        assert_eq!(graph.name(0), "b");
+        assert_eq!(graph.names().collect::<Vec<_>>(), vec!["b", "a"]);
+        assert_eq!(graph.nodes(), 0..2);
+    }
+
+    #[test]
+    fn edges() {
+        let mut graph: Graph<u32> = Graph::new();
+        assert_eq!(graph.add_edge("a", "b", 1), (0, 1));
+        graph.add_edge("a", "c", 2);
+        graph.add_undirected("b", "c", 3);
+        graph.add_undirected("c", "c", 4);
+
+        let out = |id| {
+            graph
+                .neighbors(id)
+                .map(|(n, w)| (n, *w))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(out(0), vec![(1, 1), (2, 2)]);
+        assert_eq!(out(1), vec![(2, 3)]);
+        assert_eq!(out(2), vec![(1, 3), (2, 4)]);
+
+        let into = |id| graph.incoming(id).map(|(n, w)| (n, *w)).collect::<Vec<_>>();
+        assert_eq!(into(0), vec![]);
+        assert_eq!(into(1), vec![(0, 1), (2, 3)]);
+        assert_eq!(into(2), vec![(0, 2), (1, 3), (2, 4)]);
+
+        assert_eq!(graph.weight(0, 2), Some(&2));
+        assert_eq!(graph.weight(2, 0), None);
+    }
+}
@@ -0,0 +1,498 @@
+use crate::coord::{Coord, Direction, Direction8};
+use crate::parsing::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+// A rectangular grid stored row by row in a single Vec.  Indexing with a Coord
+// panics when out of bounds; `get` is the checked version.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // Panics if the rows aren't all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+        let mut cells = Vec::with_capacity(width * height);
+        for (i, row) in rows.into_iter().enumerate() {
+            assert_eq!(row.len(), width, "row {} is the wrong length", i);
+            cells.extend(row);
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // Builds a grid by calling `f` for each coordinate, row by row.
+    pub fn from_fn<F: FnMut(Coord) -> T>(width: usize, height: usize, f: F) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: (0..width * height)
+                .map(|i| Coord::from((i / width.max(1), i % width.max(1))))
+                .map(f)
+                .collect(),
+        }
+    }
+
+    // Builds a grid from lines of text, mapping each character to a cell.
+    // Blank lines at the end of the input are ignored.
+    pub fn parse<F: FnMut(char) -> T>(input: &str, mut f: F) -> Grid<T> {
+        Grid::from_rows(
+            input
+                .trim_end_matches(['\r', '\n'])
+                .lines()
+                .map(|line| line.chars().map(&mut f).collect())
+                .collect(),
+        )
+    }
+
+    // As `parse`, but `f` can reject a character (by returning None), and rows
+    // of different lengths are an error rather than a panic.
+    pub fn try_parse<F: FnMut(char) -> Option<T>>(
+        input: &str,
+        mut f: F,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (i, line) in input.trim_end_matches(['\r', '\n']).lines().enumerate() {
+            let mut row = Vec::with_capacity(line.len());
+            for (offset, c) in line.char_indices() {
+                match f(c) {
+                    Some(cell) => row.push(cell),
+                    None => {
+                        return Err(ParseError::at(line, offset, format!("unexpected {:?}", c))
+                            .on_line(i + 1))
+                    }
+                }
+            }
+            if let Some(width) = rows.first().map(|r| r.len()) {
+                if row.len() != width {
+                    let offset = line
+                        .char_indices()
+                        .nth(width)
+                        .map_or(line.len(), |(o, _)| o);
+                    let message = format!("expected {} cells, found {}", width, row.len());
+                    return Err(ParseError::at(line, offset, message).on_line(i + 1));
+                }
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, coord: Coord) -> bool {
+        coord.row >= 0
+            && coord.col >= 0
+            && (coord.row as usize) < self.height
+            && (coord.col as usize) < self.width
+    }
+
+    fn offset(&self, coord: Coord) -> Option<usize> {
+        self.in_bounds(coord)
+            .then(|| coord.row as usize * self.width + coord.col as usize)
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.offset(coord).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.offset(coord).map(move |i| &mut self.cells[i])
+    }
+
+    // Returns the old value, or None if `coord` is off the grid.
+    pub fn set(&mut self, coord: Coord, value: T) -> Option<T> {
+        self.get_mut(coord)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+
+    // Every coordinate on the grid, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.width * self.height).map(move |i| Coord::from((i / width, i % width)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    // The in-bounds coordinates above, right of, below and left of `coord`.
+    pub fn neighbors4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.neighbors_in(coord, Direction::ALL.map(|d| d.delta()))
+    }
+
+    // As `neighbors4`, but including the diagonals.
+    pub fn neighbors8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.neighbors_in(coord, Direction8::ALL.map(|d| d.delta()))
+    }
+
+    fn neighbors_in<const N: usize>(
+        &self,
+        coord: Coord,
+        deltas: [Coord; N],
+    ) -> impl Iterator<Item = Coord> + '_ {
+        deltas
+            .into_iter()
+            .map(move |delta| coord + delta)
+            .filter(move |c| self.in_bounds(*c))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() panics on a zero width, and there are no cells to show then.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} is out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.col(col))
+    }
+
+    pub fn find(&self, value: &T) -> Option<Coord>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, v)| *v == value).map(|(c, _)| c)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Coord> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, v)| *v == value)
+            .map(|(c, _)| c)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+// Rearrangements of the cells.  Rotations are about the centre of the grid,
+// so a w x h grid comes back as h x w.
+impl<T: Clone> Grid<T> {
+    // Swaps rows and columns (mirrors about the leading diagonal).
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |c| {
+            self[Coord::new(c.col, c.row)].clone()
+        })
+    }
+
+    // Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let last_col = self.width as i64 - 1;
+        Grid::from_fn(self.width, self.height, |c| {
+            self[Coord::new(c.row, last_col - c.col)].clone()
+        })
+    }
+
+    // Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let last_row = self.height as i64 - 1;
+        Grid::from_fn(self.width, self.height, |c| {
+            self[Coord::new(last_row - c.row, c.col)].clone()
+        })
+    }
+
+    // A quarter turn clockwise.
+    pub fn rotate_right(&self) -> Grid<T> {
+        let last_row = self.height as i64 - 1;
+        Grid::from_fn(self.height, self.width, |c| {
+            self[Coord::new(last_row - c.col, c.row)].clone()
+        })
+    }
+
+    // A quarter turn anticlockwise.
+    pub fn rotate_left(&self) -> Grid<T> {
+        let last_col = self.width as i64 - 1;
+        Grid::from_fn(self.height, self.width, |c| {
+            self[Coord::new(c.col, last_col - c.row)].clone()
+        })
+    }
+
+    pub fn rotate_180(&self) -> Grid<T> {
+        let mut cells = self.cells.clone();
+        cells.reverse();
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells,
+        }
+    }
+
+    // All 8 ways of placing the grid by rotating and flipping it: the four
+    // rotations (starting with the grid as it is), then the four rotations of
+    // its mirror image.
+    pub fn orientations(&self) -> impl Iterator<Item = Grid<T>> + '_ {
+        (0..8).map(move |i| {
+            let mut grid = if i < 4 {
+                self.clone()
+            } else {
+                self.flip_horizontal()
+            };
+            for _ in 0..i % 4 {
+                grid = grid.rotate_right();
+            }
+            grid
+        })
+    }
+
+    // The `width` x `height` block whose top left corner is at `top_left`.
+    // Panics if the block doesn't fit on the grid.
+    pub fn sub_grid(&self, top_left: Coord, width: usize, height: usize) -> Grid<T> {
+        Grid::from_fn(width, height, |c| self[top_left + c].clone())
+    }
+
+    // Cuts the grid into `block_width` x `block_height` blocks.  Panics if the
+    // grid doesn't divide evenly.
+    pub fn split(&self, block_width: usize, block_height: usize) -> Grid<Grid<T>> {
+        assert!(
+            block_width > 0 && block_height > 0,
+            "can't cut a grid into {}x{} blocks",
+            block_width,
+            block_height
+        );
+        assert!(
+            self.width.is_multiple_of(block_width) && self.height.is_multiple_of(block_height),
+            "a {}x{} grid can't be cut into {}x{} blocks",
+            self.width,
+            self.height,
+            block_width,
+            block_height
+        );
+        Grid::from_fn(
+            self.width / block_width,
+            self.height / block_height,
+            |block| {
+                let top_left = Coord::new(
+                    block.row * block_height as i64,
+                    block.col * block_width as i64,
+                );
+                self.sub_grid(top_left, block_width, block_height)
+            },
+        )
+    }
+
+    // Joins blocks back into one grid, the inverse of `split`.  Blocks in the
+    // same row must have the same height, and blocks in the same column the
+    // same width.
+    pub fn stitch(blocks: &Grid<Grid<T>>) -> Grid<T> {
+        if blocks.width == 0 || blocks.height == 0 {
+            return Grid::from_rows(Vec::new());
+        }
+        let widths: Vec<usize> = blocks.row(0).iter().map(|b| b.width).collect();
+        let heights: Vec<usize> = blocks.col(0).map(|b| b.height).collect();
+        for (coord, block) in blocks.iter() {
+            assert_eq!(
+                (block.width, block.height),
+                (widths[coord.col as usize], heights[coord.row as usize]),
+                "block {} doesn't line up with its neighbours",
+                coord
+            );
+        }
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for block_row in blocks.rows() {
+            for row in 0..block_row[0].height {
+                rows.push(
+                    block_row
+                        .iter()
+                        .flat_map(|block| block.row(row).iter().cloned())
+                        .collect(),
+                );
+            }
+        }
+        Grid::from_rows(rows)
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        self.get(coord)
+            .unwrap_or_else(|| panic!("{} is outside the grid", coord))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{} is outside the grid", coord))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod grid_tests {
+    use super::*;
+
+    const SAMPLE: &str = "123\n456\n789\n";
+
+    fn digits() -> Grid<u32> {
+        Grid::parse(SAMPLE, |c| c.to_digit(10).unwrap())
+    }
+
+    #[test]
+    fn parse_and_index() {
+        let grid = digits();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[Coord::new(1, 2)], 6);
+        assert_eq!(grid.get(Coord::new(-1, 0)), None);
+        assert_eq!(grid.get(Coord::new(0, 3)), None);
+        assert_eq!(grid.to_string(), SAMPLE.trim_end());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let digit = |c: char| c.to_digit(10);
+        assert_eq!(Grid::try_parse(SAMPLE, digit), Ok(digits()));
+        let err = Grid::try_parse("123\n4x6\n", digit).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), 2));
+        assert_eq!(err.message, "unexpected 'x'");
+        let err = Grid::try_parse("123\n45\n", digit).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), 3));
+        let err = Grid::try_parse("123\n4567\n", digit).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), 4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn ragged_rows() {
+        Grid::parse("12\n3\n", |c| c);
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = digits();
+        let corner: Vec<u32> = grid.neighbors4(Coord::new(0, 0)).map(|c| grid[c]).collect();
+        assert_eq!(corner, vec![2, 4]);
+        assert_eq!(grid.neighbors8(Coord::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8(Coord::new(2, 1)).count(), 5);
+    }
+
+    #[test]
+    fn rows_and_cols() {
+        let grid = digits();
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.col(2).copied().collect::<Vec<_>>(), vec![3, 6, 9]);
+        let col_sums: Vec<u32> = grid.cols().map(|c| c.sum()).collect();
+        assert_eq!(col_sums, vec![12, 15, 18]);
+        assert_eq!(grid.rows().count(), 3);
+    }
+
+    #[test]
+    fn find_and_set() {
+        let mut grid = digits();
+        assert_eq!(grid.find(&5), Some(Coord::new(1, 1)));
+        assert_eq!(grid.set(Coord::new(2, 2), 5), Some(9));
+        assert_eq!(grid.set(Coord::new(3, 2), 5), None);
+        let fives: Vec<Coord> = grid.find_all(&5).collect();
+        assert_eq!(fives, vec![Coord::new(1, 1), Coord::new(2, 2)]);
+        assert!(grid.map(|d| d % 2 == 0)[Coord::new(0, 1)]);
+    }
+
+    #[test]
+    fn transformations() {
+        let grid = Grid::parse("123\n456\n", |c| c);
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.flip_horizontal().to_string(), "321\n654");
+        assert_eq!(grid.flip_vertical().to_string(), "456\n123");
+        assert_eq!(grid.rotate_right().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_left().to_string(), "36\n25\n14");
+        assert_eq!(grid.rotate_180().to_string(), "654\n321");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(grid.rotate_right().rotate_right(), grid.rotate_180());
+        assert_eq!(grid.flip_horizontal().rotate_left(), grid.transpose());
+    }
+
+    #[test]
+    fn orientations() {
+        let grid = Grid::parse("12\n34\n", |c| c);
+        let all: Vec<Grid<char>> = grid.orientations().collect();
+        assert_eq!(all.len(), 8);
+        assert_eq!(all[0], grid);
+        assert!(all.contains(&grid.transpose()));
+        assert!(all.contains(&grid.flip_vertical()));
+        for (i, a) in all.iter().enumerate() {
+            assert!(all[i + 1..].iter().all(|b| a != b));
+        }
+    }
+
+    #[test]
+    fn split_and_stitch() {
+        let grid = Grid::parse("abcd\nefgh\nijkl\nmnop\n", |c| c);
+        assert_eq!(
+            grid.sub_grid(Coord::new(1, 1), 2, 3).to_string(),
+            "fg\njk\nno"
+        );
+        let blocks = grid.split(2, 2);
+        assert_eq!((blocks.width(), blocks.height()), (2, 2));
+        assert_eq!(blocks[Coord::new(1, 0)].to_string(), "ij\nmn");
+        assert_eq!(Grid::stitch(&blocks), grid);
+
+        let uneven = Grid::from_rows(vec![vec![
+            Grid::parse("ab\ncd", |c| c),
+            Grid::parse("x\ny", |c| c),
+        ]]);
+        assert_eq!(Grid::stitch(&uneven).to_string(), "abx\ncdy");
+
+        let empty: Grid<char> = Grid::from_rows(Vec::new());
+        assert_eq!(Grid::stitch(&empty.split(2, 2)), empty);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't cut a grid into 0x2 blocks")]
+    fn split_into_nothing() {
+        Grid::parse("ab\ncd\n", |c| c).split(0, 2);
+    }
+}
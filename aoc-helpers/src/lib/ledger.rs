@@ -0,0 +1,256 @@
+use crate::submit::SubmitResult;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+// One line of the ledger: an answer we posted and what the site said about it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub verdict: SubmitResult,
+}
+
+// Why an answer shouldn't be submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved { answer: Option<String> },
+    AlreadySubmitted(SubmitResult),
+    NotBelow(String),
+    NotAbove(String),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer: Some(a) } => {
+                write!(f, "already solved; the accepted answer was {}", a)
+            }
+            Refusal::AlreadySolved { answer: None } => write!(f, "already solved"),
+            Refusal::AlreadySubmitted(verdict) => {
+                write!(f, "already submitted this answer ({})", verdict)
+            }
+            Refusal::NotBelow(high) => write!(f, "{} was already too high", high),
+            Refusal::NotAbove(low) => write!(f, "{} was already too low", low),
+        }
+    }
+}
+
+// Everything that's been submitted for a single year, stored as tab separated
+// `day part verdict answer` lines in `<cache dir>/<year>/submissions.tsv`.
+pub struct Ledger {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl Ledger {
+    pub fn path_for(cache_dir: &Path, year: u32) -> PathBuf {
+        cache_dir.join(year.to_string()).join("submissions.tsv")
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Ledger> {
+        let path = path.as_ref().to_path_buf();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        let mut submissions = Vec::new();
+        for (i, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let submission = parse_line(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}:{}: malformed ledger entry", path.display(), i + 1),
+                )
+            })?;
+            submissions.push(submission);
+        }
+        Ok(Ledger { path, submissions })
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    pub fn check(&self, day: u32, part: u32, answer: &str) -> Result<(), Refusal> {
+        let answer = answer.trim();
+        let mut lowest_high: Option<(i128, &str)> = None;
+        let mut highest_low: Option<(i128, &str)> = None;
+        for s in self
+            .submissions
+            .iter()
+            .filter(|s| s.day == day && s.part == part)
+        {
+            match s.verdict {
+                SubmitResult::Correct => {
+                    return Err(Refusal::AlreadySolved {
+                        answer: Some(s.answer.clone()),
+                    })
+                }
+                SubmitResult::AlreadySolved => return Err(Refusal::AlreadySolved { answer: None }),
+                _ => {}
+            }
+            if s.answer == answer {
+                return Err(Refusal::AlreadySubmitted(s.verdict.clone()));
+            }
+            let n = match s.answer.parse::<i128>() {
+                Ok(n) => n,
+                Err(_) => continue,
+            };
+            match s.verdict {
+                SubmitResult::TooHigh if lowest_high.is_none_or(|(h, _)| n < h) => {
+                    lowest_high = Some((n, &s.answer))
+                }
+                SubmitResult::TooLow if highest_low.is_none_or(|(l, _)| n > l) => {
+                    highest_low = Some((n, &s.answer))
+                }
+                _ => {}
+            }
+        }
+        if let Ok(n) = answer.parse::<i128>() {
+            if let Some((high, text)) = lowest_high {
+                if n >= high {
+                    return Err(Refusal::NotBelow(text.to_string()));
+                }
+            }
+            if let Some((low, text)) = highest_low {
+                if n <= low {
+                    return Err(Refusal::NotAbove(text.to_string()));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Only verdicts that tell us something about the answer get recorded; being
+    // rate limited or getting a page we don't understand says nothing.
+    pub fn record(
+        &mut self,
+        day: u32,
+        part: u32,
+        answer: &str,
+        verdict: &SubmitResult,
+    ) -> io::Result<()> {
+        if verdict_name(verdict).is_none() {
+            return Ok(());
+        }
+        let submission = Submission {
+            day,
+            part,
+            answer: answer.trim().to_string(),
+            verdict: verdict.clone(),
+        };
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", format_line(&submission))?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+}
+
+fn verdict_name(verdict: &SubmitResult) -> Option<&'static str> {
+    match verdict {
+        SubmitResult::Correct => Some("correct"),
+        SubmitResult::TooHigh => Some("too_high"),
+        SubmitResult::TooLow => Some("too_low"),
+        SubmitResult::Wrong => Some("wrong"),
+        SubmitResult::AlreadySolved => Some("already_solved"),
+        SubmitResult::RateLimited { .. } | SubmitResult::Unknown(_) => None,
+    }
+}
+
+fn format_line(s: &Submission) -> String {
+    format!(
+        "{}\t{}\t{}\t{}",
+        s.day,
+        s.part,
+        verdict_name(&s.verdict).unwrap_or("unknown"),
+        s.answer
+    )
+}
+
+fn parse_line(line: &str) -> Option<Submission> {
+    let mut fields = line.splitn(4, '\t');
+    let day = fields.next()?.parse().ok()?;
+    let part = fields.next()?.parse().ok()?;
+    let verdict = match fields.next()? {
+        "correct" => SubmitResult::Correct,
+        "too_high" => SubmitResult::TooHigh,
+        "too_low" => SubmitResult::TooLow,
+        "wrong" => SubmitResult::Wrong,
+        "already_solved" => SubmitResult::AlreadySolved,
+        _ => return None,
+    };
+    let answer = fields.next()?.to_string();
+    Some(Submission {
+        day,
+        part,
+        answer,
+        verdict,
+    })
+}
+
+#[cfg(test)]
+mod ledger_tests {
+    use super::*;
+    use std::env;
+
+    fn temp_ledger(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-ledger-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Ledger::path_for(&dir, 2023)
+    }
+
+    #[test]
+    fn bounds_and_duplicates() {
+        let path = temp_ledger("bounds");
+        let mut ledger = Ledger::load(&path).unwrap();
+        assert_eq!(ledger.check(5, 1, "100"), Ok(()));
+
+        ledger.record(5, 1, "100", &SubmitResult::TooHigh).unwrap();
+        ledger.record(5, 1, "10", &SubmitResult::TooLow).unwrap();
+        ledger.record(5, 1, "50", &SubmitResult::Wrong).unwrap();
+        ledger
+            .record(5, 1, "60", &SubmitResult::RateLimited { wait: None })
+            .unwrap();
+
+        assert_eq!(
+            ledger.check(5, 1, "50"),
+            Err(Refusal::AlreadySubmitted(SubmitResult::Wrong))
+        );
+        assert_eq!(
+            ledger.check(5, 1, "150"),
+            Err(Refusal::NotBelow("100".to_string()))
+        );
+        assert_eq!(
+            ledger.check(5, 1, "-3"),
+            Err(Refusal::NotAbove("10".to_string()))
+        );
+        assert_eq!(ledger.check(5, 1, "60"), Ok(()));
+        assert_eq!(ledger.check(5, 2, "150"), Ok(()));
+
+        // Everything but the rate limited attempt survives a reload.
+        let reloaded = Ledger::load(&path).unwrap();
+        assert_eq!(reloaded.submissions().len(), 3);
+
+        ledger.record(5, 1, "42", &SubmitResult::Correct).unwrap();
+        assert_eq!(
+            Ledger::load(&path).unwrap().check(5, 1, "43"),
+            Err(Refusal::AlreadySolved {
+                answer: Some("42".to_string())
+            })
+        );
+
+        fs::remove_dir_all(path.parent().unwrap().parent().unwrap()).unwrap();
+    }
+}
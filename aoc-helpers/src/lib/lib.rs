@@ -1,6 +1,24 @@
+pub mod answer;
+pub mod answers;
+pub mod automaton;
+pub mod bench;
+pub mod client;
+pub mod coord;
+pub mod graph;
+pub mod grid;
+pub mod ledger;
+pub mod matcher;
 pub mod math;
+pub mod options;
 pub mod parsing;
+pub mod record;
+pub mod registry;
+pub mod regression;
 pub mod runner;
+pub mod search;
+pub mod session;
+pub mod sparse;
+pub mod submit;
 
 #[macro_use]
 extern crate lazy_static;
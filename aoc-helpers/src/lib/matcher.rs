@@ -0,0 +1,216 @@
+use std::collections::{HashMap, VecDeque};
+
+// One occurrence of a pattern: the bytes start..end of the text, and the value
+// the pattern was given.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Match<'a, V> {
+    pub start: usize,
+    pub end: usize,
+    pub value: &'a V,
+}
+
+// Derived Clone/Copy would needlessly require V: Copy.
+impl<V> Clone for Match<'_, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V> Copy for Match<'_, V> {}
+
+#[derive(Default)]
+struct Node {
+    next: HashMap<u8, usize>,
+    // Where to carry on from when no edge fits: the node for the longest
+    // proper suffix of this one that's also in the trie.
+    fail: usize,
+    // The patterns that end here, longest first (including those reached
+    // through fail links).
+    outputs: Vec<usize>,
+}
+
+// Finds any number of patterns in a single pass over the text, by building
+// them into a trie with Aho-Corasick fail links.  Each pattern maps to a
+// value, so "one" => 1 and "1" => 1 can both be looked for at once.
+pub struct Matcher<V> {
+    nodes: Vec<Node>,
+    // Each pattern's length and value.
+    patterns: Vec<(usize, V)>,
+    longest: usize,
+}
+
+impl<V> Matcher<V> {
+    pub fn new<I, S>(patterns: I) -> Matcher<V>
+    where
+        I: IntoIterator<Item = (S, V)>,
+        S: AsRef<str>,
+    {
+        let mut matcher = Matcher {
+            nodes: vec![Node::default()],
+            patterns: Vec::new(),
+            longest: 0,
+        };
+        for (pattern, value) in patterns {
+            let bytes = pattern.as_ref().as_bytes();
+            assert!(!bytes.is_empty(), "patterns can't be empty");
+            let mut node = 0;
+            for &b in bytes {
+                node = match matcher.nodes[node].next.get(&b) {
+                    Some(&n) => n,
+                    None => {
+                        matcher.nodes.push(Node::default());
+                        let n = matcher.nodes.len() - 1;
+                        matcher.nodes[node].next.insert(b, n);
+                        n
+                    }
+                };
+            }
+            matcher.nodes[node].outputs.push(matcher.patterns.len());
+            matcher.patterns.push((bytes.len(), value));
+            matcher.longest = matcher.longest.max(bytes.len());
+        }
+        matcher.link();
+        matcher
+    }
+
+    // Fills in the fail links breadth first, so that a node's fail target
+    // (which is always shallower) is finished before the node itself.
+    fn link(&mut self) {
+        let mut queue: VecDeque<usize> = self.nodes[0].next.values().copied().collect();
+        while let Some(node) = queue.pop_front() {
+            let edges: Vec<(u8, usize)> = self.nodes[node]
+                .next
+                .iter()
+                .map(|(&b, &n)| (b, n))
+                .collect();
+            for (b, child) in edges {
+                let mut fail = self.nodes[node].fail;
+                let target = loop {
+                    if let Some(&n) = self.nodes[fail].next.get(&b) {
+                        break n;
+                    }
+                    if fail == 0 {
+                        break 0;
+                    }
+                    fail = self.nodes[fail].fail;
+                };
+                self.nodes[child].fail = target;
+                let inherited = self.nodes[target].outputs.clone();
+                self.nodes[child].outputs.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+    }
+
+    fn step(&self, mut node: usize, b: u8) -> usize {
+        loop {
+            if let Some(&n) = self.nodes[node].next.get(&b) {
+                return n;
+            }
+            if node == 0 {
+                return 0;
+            }
+            node = self.nodes[node].fail;
+        }
+    }
+
+    // Every match in `text`, overlapping ones included, in order of where
+    // they end (and longest first when several end at the same place).
+    pub fn find_iter<'a>(&'a self, text: &'a str) -> impl Iterator<Item = Match<'a, V>> + 'a {
+        text.bytes()
+            .enumerate()
+            .scan(0, move |node, (i, b)| {
+                *node = self.step(*node, b);
+                Some((i + 1, *node))
+            })
+            .flat_map(move |(end, node)| {
+                self.nodes[node].outputs.iter().map(move |&p| {
+                    let (len, ref value) = self.patterns[p];
+                    Match {
+                        start: end - len,
+                        end,
+                        value,
+                    }
+                })
+            })
+    }
+
+    // The match that starts earliest (the longest, if several start there).
+    pub fn first<'a>(&'a self, text: &'a str) -> Option<Match<'a, V>> {
+        let mut best: Option<Match<V>> = None;
+        for m in self.find_iter(text) {
+            // Nothing ending from here on can start before the best so far.
+            if best.is_some_and(|b| m.end > b.start + self.longest) {
+                break;
+            }
+            if best.is_none_or(|b| (m.start, b.end) < (b.start, m.end)) {
+                best = Some(m);
+            }
+        }
+        best
+    }
+
+    // The match that starts latest (the longest, if several start there).
+    pub fn last<'a>(&'a self, text: &'a str) -> Option<Match<'a, V>> {
+        let mut best: Option<Match<V>> = None;
+        for m in self.find_iter(text) {
+            if best.is_none_or(|b| (m.start, m.end) > (b.start, b.end)) {
+                best = Some(m);
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod matcher_tests {
+    use super::*;
+
+    fn digits() -> Matcher<u8> {
+        let words = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+        Matcher::new(
+            words
+                .iter()
+                .zip(1..)
+                .map(|(w, v)| (w.to_string(), v))
+                .chain((1..=9).map(|v| (v.to_string(), v))),
+        )
+    }
+
+    #[test]
+    fn overlapping() {
+        let matcher = digits();
+        let found: Vec<(usize, u8)> = matcher
+            .find_iter("xtwone3four")
+            .map(|m| (m.start, *m.value))
+            .collect();
+        assert_eq!(found, vec![(1, 2), (3, 1), (6, 3), (7, 4)]);
+        assert_eq!(matcher.first("zoneight234").map(|m| *m.value), Some(1));
+        assert_eq!(matcher.last("zoneight").map(|m| *m.value), Some(8));
+        assert_eq!(matcher.first("abc"), None);
+        assert_eq!(matcher.last(""), None);
+    }
+
+    #[test]
+    fn nested_patterns() {
+        // "he", "she", "his", "hers" is the classic example.
+        let matcher = Matcher::new([("he", 'a'), ("she", 'b'), ("his", 'c'), ("hers", 'd')]);
+        let found: Vec<(usize, usize, char)> = matcher
+            .find_iter("ushers")
+            .map(|m| (m.start, m.end, *m.value))
+            .collect();
+        assert_eq!(found, vec![(1, 4, 'b'), (2, 4, 'a'), (2, 6, 'd')]);
+
+        let m = matcher.first("ushers").unwrap();
+        assert_eq!((m.start, *m.value), (1, 'b'));
+        // "he" and "hers" both start at 2; the longer one wins.
+        let m = matcher.last("ushers").unwrap();
+        assert_eq!((m.start, *m.value), (2, 'd'));
+
+        // A match that ends later can still start earlier.
+        let matcher = Matcher::new([("abcd", 1), ("bc", 2)]);
+        assert_eq!(matcher.first("abcd").map(|m| *m.value), Some(1));
+    }
+}
@@ -1,16 +1,353 @@
-pub fn gcd(mut a: u64, mut b: u64) -> u64 {
-    if a == 0 || b == 0 {
-        return 1;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Div, Rem};
+
+// The primitive integer types, signed or not, so that the functions here work
+// for whichever width a puzzle needs.
+pub trait Integer: Copy + Ord + Debug + Div<Output = Self> + Rem<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+    const TWO: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+
+    // None if the result doesn't fit (the most negative value of a signed
+    // type).
+    fn checked_abs(self) -> Option<Self> {
+        if self < Self::ZERO {
+            Self::ZERO.checked_sub(self)
+        } else {
+            Some(self)
+        }
+    }
+}
+
+// Integers that can be negative, for results like extended_gcd's
+// coefficients.
+pub trait Signed: Integer {}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const TWO: Self = 2;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+            fn checked_div(self, other: Self) -> Option<Self> {
+                <$t>::checked_div(self, other)
+            }
+            fn checked_rem(self, other: Self) -> Option<Self> {
+                <$t>::checked_rem(self, other)
+            }
+        })*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
+
+// The greatest common divisor, which is never negative.  gcd(a, 0) is |a|, so
+// this only fails for a signed type's most negative value.
+pub fn gcd<T: Integer>(mut a: T, mut b: T) -> Option<T> {
+    while b != T::ZERO {
+        // Only MIN % -1 fails, and that's 0.
+        (a, b) = (b, a.checked_rem(b).unwrap_or(T::ZERO));
+    }
+    a.checked_abs()
+}
+
+// The least common multiple, or None if it doesn't fit in T.  The lcm of
+// anything and 0 is 0.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    // Dividing first keeps the intermediate value no bigger than the answer.
+    (a / gcd(a, b)?).checked_mul(b)?.checked_abs()
+}
+
+// The lcm of every value, or 1 if there aren't any.
+pub fn lcm_all<T: Integer, I: IntoIterator<Item = T>>(values: I) -> Option<T> {
+    values.into_iter().try_fold(T::ONE, lcm)
+}
+
+// Returns (g, x, y) such that a*x + b*y = g, where g is gcd(a, b).
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> Option<(T, T, T)> {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (T::ONE, T::ZERO);
+    let (mut y0, mut y1) = (T::ZERO, T::ONE);
+    while r1 != T::ZERO {
+        let q = r0.checked_div(r1)?;
+        (r0, r1) = (r1, r0.checked_sub(q.checked_mul(r1)?)?);
+        (x0, x1) = (x1, x0.checked_sub(q.checked_mul(x1)?)?);
+        (y0, y1) = (y1, y0.checked_sub(q.checked_mul(y1)?)?);
+    }
+    if r0 < T::ZERO {
+        let negate = |v: T| T::ZERO.checked_sub(v);
+        Some((negate(r0)?, negate(x0)?, negate(y0)?))
+    } else {
+        Some((r0, x0, y0))
+    }
+}
+
+// `a` mod `m` in 0..m, even when `a` is negative.  `m` must be positive.
+fn reduce<T: Integer>(a: T, m: T) -> T {
+    let r = a % m;
+    if r < T::ZERO {
+        r.checked_add(m).unwrap()
+    } else {
+        r
+    }
+}
+
+// These take values already reduced into 0..m, and can't overflow whatever
+// the size of m.
+fn add_mod<T: Integer>(a: T, b: T, m: T) -> T {
+    let gap = m.checked_sub(b).unwrap();
+    if a >= gap {
+        a.checked_sub(gap).unwrap()
+    } else {
+        a.checked_add(b).unwrap()
     }
-    while b != 0 {
-        if b < a {
-            std::mem::swap(&mut b, &mut a);
+}
+
+fn sub_mod<T: Integer>(a: T, b: T, m: T) -> T {
+    if a >= b {
+        a.checked_sub(b).unwrap()
+    } else {
+        m.checked_sub(b.checked_sub(a).unwrap()).unwrap()
+    }
+}
+
+fn mul_mod<T: Integer>(a: T, mut b: T, m: T) -> T {
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+    // Too big to multiply directly, so build it up by doubling.
+    let mut result = T::ZERO;
+    let mut a = a;
+    while b > T::ZERO {
+        if b % T::TWO == T::ONE {
+            result = add_mod(result, a, m);
         }
-        b %= a;
+        a = add_mod(a, a, m);
+        b = b / T::TWO;
     }
-    a
+    result
 }
 
-pub fn lcm(a: u64, b: u64) -> u64 {
-    (a * b) / gcd(a, b)
+// `base` to the power `exp`, mod `m`.  None if `m` isn't positive or `exp` is
+// negative.
+pub fn mod_pow<T: Integer>(base: T, mut exp: T, m: T) -> Option<T> {
+    if m <= T::ZERO || exp < T::ZERO {
+        return None;
+    }
+    let mut base = reduce(base, m);
+    let mut result = reduce(T::ONE, m);
+    while exp > T::ZERO {
+        if exp % T::TWO == T::ONE {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp = exp / T::TWO;
+    }
+    Some(result)
+}
+
+// The x in 0..m with a*x = 1 (mod m), if there is one (that is, if a and m
+// are coprime and m is positive).
+pub fn mod_inverse<T: Integer>(a: T, m: T) -> Option<T> {
+    if m <= T::ZERO {
+        return None;
+    }
+    // Extended Euclid, but keeping the coefficient mod m so that it never goes
+    // negative.
+    let (mut r0, mut r1) = (m, reduce(a, m));
+    let (mut t0, mut t1) = (T::ZERO, reduce(T::ONE, m));
+    while r1 != T::ZERO {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 % r1);
+        (t0, t1) = (t1, sub_mod(t0, mul_mod(reduce(q, m), t1, m), m));
+    }
+    if r0 == T::ONE {
+        Some(t0)
+    } else {
+        None
+    }
+}
+
+// Solves x = a (mod m) for every (a, m) in `congruences`.  The moduli don't
+// need to be coprime.  Returns the smallest non-negative x along with the lcm
+// of the moduli (every solution is x plus a multiple of it), or None if there
+// is no solution, a modulus isn't positive, or the lcm doesn't fit in T.
+pub fn crt<T: Integer>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let mut x = T::ZERO;
+    let mut l = T::ONE;
+    for &(a, m) in congruences {
+        if m <= T::ZERO {
+            return None;
+        }
+        // x + l*k = a (mod m), so l*k = a - x, which needs gcd(l, m) to divide
+        // a - x.
+        let g = gcd(l, m)?;
+        let diff = sub_mod(reduce(a, m), reduce(x, m), m);
+        if diff % g != T::ZERO {
+            return None;
+        }
+        let step = m / g;
+        let k = mul_mod(diff / g, mod_inverse(l / g, step)?, step);
+        let next = (l / g).checked_mul(m)?;
+        // k < step, so l*k < next.
+        x = add_mod(x, l.checked_mul(k)?, next);
+        l = next;
+    }
+    Some((x, l))
+}
+
+// The smallest x >= 0 with base^x = target (mod m), if there is one, using
+// baby-step giant-step so that it takes about sqrt(m) steps (and memory)
+// rather than m.
+pub fn discrete_log<T: Integer + Hash>(base: T, target: T, m: T) -> Option<T> {
+    if m <= T::ZERO {
+        return None;
+    }
+    let (base, mut target, mut m) = (reduce(base, m), reduce(target, m), m);
+    // Solve factor * base^x = target, first dividing out any factors base has
+    // in common with m (each of which uses up one power of base).
+    let mut factor = reduce(T::ONE, m);
+    let mut skipped = T::ZERO;
+    loop {
+        if factor == target {
+            return Some(skipped);
+        }
+        let g = gcd(base, m)?;
+        if g == T::ONE {
+            break;
+        }
+        if target % g != T::ZERO {
+            return None;
+        }
+        target = target / g;
+        m = m / g;
+        factor = mul_mod(reduce(factor, m), reduce(base / g, m), m);
+        skipped = skipped.checked_add(T::ONE)?;
+    }
+    let base = reduce(base, m);
+
+    // Write x as i*n - j.  The baby steps remember target * base^j for each j
+    // (the largest j wins, giving the smallest x), then the giant steps look
+    // for factor * base^(i*n) among them.
+    let mut n = T::ONE;
+    while n.checked_mul(n).is_some_and(|square| square < m) {
+        n = n.checked_add(T::ONE)?;
+    }
+    let mut baby: HashMap<T, T> = HashMap::new();
+    let mut value = target;
+    let mut j = T::ZERO;
+    while j < n {
+        baby.insert(value, j);
+        value = mul_mod(value, base, m);
+        j = j.checked_add(T::ONE)?;
+    }
+    let giant = mod_pow(base, n, m)?;
+    let mut value = factor;
+    let mut i = T::ONE;
+    while i <= n {
+        value = mul_mod(value, giant, m);
+        if let Some(&j) = baby.get(&value) {
+            return i.checked_mul(n)?.checked_sub(j)?.checked_add(skipped);
+        }
+        i = i.checked_add(T::ONE)?;
+    }
+    None
+}
+
+#[cfg(test)]
+mod math_tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12u64, 18), Some(6));
+        assert_eq!(gcd(0u8, 7), Some(7));
+        assert_eq!(gcd(0u32, 0), Some(0));
+        assert_eq!(gcd(-12i32, 18), Some(6));
+        assert_eq!(gcd(i8::MIN, 0), None);
+        assert_eq!(gcd(i8::MIN, -1), Some(1));
+
+        assert_eq!(lcm(4u64, 6), Some(12));
+        assert_eq!(lcm(-4i64, 6), Some(12));
+        assert_eq!(lcm(0u16, 6), Some(0));
+        // 2^63 fits, and so does 2^63 * 2^62 / 2^62 when divided first.
+        assert_eq!(lcm(1u64 << 63, 1 << 62), Some(1 << 63));
+        assert_eq!(lcm(1u64 << 63, 3), None);
+        assert_eq!(lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+        assert_eq!(lcm_all([2u32, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all(Vec::<u8>::new()), Some(1));
+        assert_eq!(lcm_all([200u8, 3]), None);
+    }
+
+    #[test]
+    fn modular() {
+        assert_eq!(extended_gcd(240i64, 46), Some((2, -9, 47)));
+        assert_eq!(extended_gcd(-6i32, 4), Some((2, -1, -1)));
+
+        assert_eq!(mod_inverse(3u32, 11), Some(4));
+        assert_eq!(mod_inverse(-3i64, 11), Some(7));
+        assert_eq!(mod_inverse(6u8, 9), None);
+        assert_eq!(mod_inverse(5u64, 0), None);
+
+        assert_eq!(mod_pow(4u32, 13, 497), Some(445));
+        assert_eq!(mod_pow(-2i32, 3, 5), Some(2));
+        assert_eq!(mod_pow(7u8, 0, 1), Some(0));
+        assert_eq!(mod_pow(2i64, -1, 5), None);
+        // Products that don't fit in the type still work.
+        assert_eq!(mod_pow(u64::MAX - 1, 2, u64::MAX), Some(1));
+        assert_eq!(mod_inverse(u64::MAX - 1, u64::MAX), Some(u64::MAX - 1));
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt(&[(2u64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(-1i64, 4), (3, 6)]), Some((3, 12)));
+        assert_eq!(crt(&[(1i64, 4), (2, 6)]), None);
+        assert_eq!(crt::<u8>(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(1u8, 16), (2, 17)]), None);
+        assert_eq!(crt(&[(1u8, 0)]), None);
+    }
+
+    #[test]
+    fn discrete_logs() {
+        assert_eq!(discrete_log(7u64, 5764801, 20201227), Some(8));
+        assert_eq!(discrete_log(7u64, 17807724, 20201227), Some(11));
+        assert_eq!(discrete_log(3i32, 13, 17), Some(4));
+        assert_eq!(discrete_log(3u32, 1, 17), Some(0));
+        assert_eq!(discrete_log(4u8, 2, 7), Some(2));
+        assert_eq!(discrete_log(2u8, 3, 7), None);
+        // Bases that share factors with the modulus.
+        assert_eq!(discrete_log(2u32, 8, 24), Some(3));
+        assert_eq!(discrete_log(2u32, 16, 24), Some(4));
+        assert_eq!(discrete_log(2u32, 3, 4), None);
+        assert_eq!(discrete_log(6u64, 0, 36), Some(2));
+        assert_eq!(discrete_log(5u16, 3, 1), Some(0));
+    }
 }
@@ -0,0 +1,207 @@
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+
+pub const USAGE: &str = "Options:
+    --part <1|2|both>     which part(s) to run (default: both)
+    --input <file|->      read the puzzle input from a file or stdin instead of
+                          the cached/downloaded input
+    --submit              submit the answers to adventofcode.com (only for
+                          the real puzzle input, not with --input)
+    --no-submit           never submit (the default when any option is given)
+    --bench <runs>        time each part over this many runs instead of
+                          printing/submitting the answer
+    --refresh             re-download the puzzle input even if it's cached
+    --session <token>     AoC session token (see also AOC_SESSION)
+    -h, --help            print this message
+
+With no options and an interactive terminal, you'll be prompted instead.";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartSelection {
+    One,
+    Two,
+    Both,
+}
+
+impl PartSelection {
+    pub fn includes(&self, part: u32) -> bool {
+        matches!(
+            (self, part),
+            (PartSelection::Both, _) | (PartSelection::One, 1) | (PartSelection::Two, 2)
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Puzzle,
+    Stdin,
+    File(PathBuf),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunOptions {
+    pub parts: Option<PartSelection>,
+    pub input: Option<InputSource>,
+    pub submit: Option<bool>,
+    pub refresh: bool,
+    pub bench: Option<usize>,
+    pub help: bool,
+    // Whether any option was given at all, since prompting is only a fallback
+    // for when none were.
+    pub saw_flag: bool,
+}
+
+impl RunOptions {
+    // `args` shouldn't include the program name.
+    pub fn parse(args: &[String]) -> Result<RunOptions, String> {
+        let mut options = RunOptions {
+            parts: None,
+            input: None,
+            submit: None,
+            refresh: false,
+            bench: None,
+            help: false,
+            saw_flag: false,
+        };
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            options.saw_flag = true;
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_string())),
+                _ => (arg.as_str(), None),
+            };
+            let mut value = || {
+                inline
+                    .clone()
+                    .or_else(|| iter.next().cloned())
+                    .ok_or(format!("{} expects a value", flag))
+            };
+            match flag {
+                "--part" => {
+                    options.parts = Some(match value()?.as_str() {
+                        "1" => PartSelection::One,
+                        "2" => PartSelection::Two,
+                        "both" => PartSelection::Both,
+                        other => return Err(format!("invalid part '{}'", other)),
+                    })
+                }
+                "--input" => {
+                    let v = value()?;
+                    options.input = Some(if v == "-" {
+                        InputSource::Stdin
+                    } else {
+                        InputSource::File(PathBuf::from(v))
+                    });
+                }
+                "--submit" => options.submit = Some(true),
+                "--no-submit" => options.submit = Some(false),
+                "--refresh" => options.refresh = true,
+                "--bench" => {
+                    let v = value()?;
+                    options.bench = match v.parse::<usize>() {
+                        Ok(n) if n > 0 => Some(n),
+                        _ => return Err(format!("invalid number of runs '{}'", v)),
+                    };
+                }
+                // Handled by session::find_session.
+                "--session" => {
+                    value()?;
+                }
+                "-h" | "--help" => options.help = true,
+                other => return Err(format!("unrecognized argument '{}'", other)),
+            }
+        }
+        options.validate()?;
+        Ok(options)
+    }
+
+    // Answers worked out from some other input would be wrong for the real
+    // puzzle, and too many wrong answers earn a lockout.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.submit() && self.input() != InputSource::Puzzle {
+            return Err("--submit only works with the real puzzle input, not --input".to_string());
+        }
+        Ok(())
+    }
+
+    // Prompting only makes sense when nobody told us what to do and there's
+    // somebody at the keyboard to answer.
+    pub fn is_interactive(&self) -> bool {
+        !self.saw_flag && io::stdin().is_terminal()
+    }
+
+    pub fn parts(&self) -> PartSelection {
+        self.parts.unwrap_or(PartSelection::Both)
+    }
+
+    pub fn input(&self) -> InputSource {
+        self.input.clone().unwrap_or(InputSource::Puzzle)
+    }
+
+    pub fn submit(&self) -> bool {
+        self.submit.unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod options_tests {
+    use super::*;
+
+    fn parse(a: &[&str]) -> Result<RunOptions, String> {
+        let args: Vec<String> = a.iter().map(|s| s.to_string()).collect();
+        RunOptions::parse(&args)
+    }
+
+    #[test]
+    fn defaults() {
+        let options = parse(&[]).unwrap();
+        assert_eq!(options.parts(), PartSelection::Both);
+        assert_eq!(options.input(), InputSource::Puzzle);
+        assert!(!options.submit());
+        assert!(!options.refresh);
+        assert!(!options.saw_flag);
+    }
+
+    #[test]
+    fn any_flag_skips_prompts() {
+        // Even flags that don't pick parts or input mean nobody wants prompting.
+        let options = parse(&["--refresh"]).unwrap();
+        assert!(options.saw_flag);
+        assert!(!options.is_interactive());
+        assert!(!parse(&["--session", "x"]).unwrap().is_interactive());
+    }
+
+    #[test]
+    fn flags() {
+        let options = parse(&["--part", "2", "--submit", "--refresh"]).unwrap();
+        assert_eq!(options.parts, Some(PartSelection::Two));
+        assert_eq!(options.submit, Some(true));
+        assert!(options.refresh);
+        assert!(!options.is_interactive());
+
+        let options = parse(&["--input=-"]).unwrap();
+        assert_eq!(options.input, Some(InputSource::Stdin));
+
+        let options = parse(&["--input", "sample.txt", "--no-submit", "--session", "x"]).unwrap();
+        assert_eq!(
+            options.input,
+            Some(InputSource::File(PathBuf::from("sample.txt")))
+        );
+        assert_eq!(options.submit, Some(false));
+        assert!(PartSelection::Both.includes(1) && PartSelection::Both.includes(2));
+        assert!(!PartSelection::One.includes(2));
+    }
+
+    #[test]
+    fn bad_flags() {
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--part"]).is_err());
+        assert!(parse(&["--bogus"]).is_err());
+        // Only answers for the real puzzle input can be submitted.
+        assert!(parse(&["--input", "sample.txt", "--submit"]).is_err());
+        assert!(parse(&["--submit", "--input=-"]).is_err());
+        assert!(parse(&["--bench", "0"]).is_err());
+        assert_eq!(parse(&["--bench", "10"]).unwrap().bench, Some(10));
+    }
+}
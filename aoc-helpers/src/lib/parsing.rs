@@ -1,118 +1,289 @@
+use crate::matcher::Matcher;
 use nom::bytes::complete::*;
 use nom::character::complete::*;
 use nom::IResult;
-use std::collections::HashMap;
-use std::str::Lines;
+use std::any::type_name;
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
 
 lazy_static! {
-    static ref DIGITS: HashMap<&'static str, u8> = {
-        let mut m = HashMap::new();
-        m.insert("1", 1u8);
-        m.insert("2", 2u8);
-        m.insert("3", 3u8);
-        m.insert("4", 4u8);
-        m.insert("5", 5u8);
-        m.insert("6", 6u8);
-        m.insert("7", 7u8);
-        m.insert("8", 8u8);
-        m.insert("9", 9u8);
-        m.insert("0", 0u8);
-        m
-    };
-    static ref TEXT_DIGITS: HashMap<&'static str, u8> = {
-        let mut m = HashMap::new();
-        m.insert("one", 1u8);
-        m.insert("two", 2u8);
-        m.insert("three", 3u8);
-        m.insert("four", 4u8);
-        m.insert("five", 5u8);
-        m.insert("six", 6u8);
-        m.insert("seven", 7u8);
-        m.insert("eight", 8u8);
-        m.insert("nine", 9u8);
-        m.insert("zero", 0u8);
-        m
-    };
-}
-
-pub type Matrix = Vec<Vec<char>>;
-pub type I32Matrix = Vec<Vec<i32>>;
-
-pub fn parse_matrix(s: &str) -> Matrix {
-    let mut matrix: Matrix = Vec::with_capacity(s.lines().count());
-    for l in s.lines() {
-        let row: Vec<char> = l.chars().collect();
-        matrix.push(row);
-    }
-    matrix
-}
-
-pub fn parse_num_matrix(s: &str) -> I32Matrix {
-    let mut matrix: I32Matrix = Vec::with_capacity(s.lines().count());
-    for l in s.lines() {
-        let row: Vec<i32> = l.chars().map(|c| (c as u8 - '0' as u8) as i32).collect();
-        matrix.push(row);
-    }
-    matrix
-}
-
-pub fn matrix_from_lines(l: &mut Lines<'_>) -> Matrix {
-    let mut matrix: Matrix = Vec::new();
-    while let Some(line) = l.next() {
-        if line == "" {
-            break;
+    static ref DIGITS: Matcher<u8> = Matcher::new((0..=9).map(|d| (d.to_string(), d)));
+    static ref TEXT_DIGITS: Matcher<u8> = Matcher::new(
+        ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine"]
+            .iter()
+            .zip(0..)
+            .map(|(word, d)| (word.to_string(), d))
+            .chain((0..=9).map(|d| (d.to_string(), d))),
+    );
+}
+
+// Something in the input that couldn't be parsed.  `line` and `column` count
+// from 1, and `text` is the line (or part of it) that the column refers to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub column: usize,
+    pub message: String,
+    pub text: String,
+}
+
+impl ParseError {
+    // `offset` is a byte offset into `text`.
+    pub fn at(text: &str, offset: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: None,
+            column: text[..offset].chars().count() + 1,
+            message: message.into(),
+            text: text.to_string(),
         }
-        let row: Vec<char> = line.chars().collect();
-        matrix.push(row);
     }
-    matrix
+
+    pub fn on_line(mut self, line: usize) -> ParseError {
+        self.line = Some(line);
+        self
+    }
+
+    // For an error found in a piece of a longer line: makes the column count
+    // from the start of `outer`, in which the piece started at byte `offset`.
+    pub fn within(mut self, outer: &str, offset: usize) -> ParseError {
+        self.column += outer[..offset].chars().count();
+        self.text = outer.to_string();
+        self
+    }
 }
 
-pub fn print_matrix(matrix: &Matrix) {
-    for row in matrix {
-        for val in row {
-            print!("{}", val);
+// Shows the offending text with a caret under the column.
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {}, ", line)?;
         }
-        println!("");
+        writeln!(f, "column {}: {}", self.column, self.message)?;
+        writeln!(f, "  {}", self.text)?;
+        write!(f, "  {:>width$}", "^", width = self.column)
     }
 }
 
-pub fn get_next_digit(s: &str, inc_text: bool, consume: bool) -> (&str, Option<u8>) {
-    let mut remainder = s;
-    while remainder != "" {
-        for (key, val) in DIGITS.iter() {
-            if let Ok((rem, _)) = take_tag(remainder, *key) {
-                if consume {
-                    return (rem, Some(*val));
-                }
-                if let Ok((rem, _)) = take_n(remainder, 1) {
-                    return (rem, Some(*val));
-                } else {
-                    return ("", Some(*val));
-                }
-            }
+impl std::error::Error for ParseError {}
+
+// Parses each line of `input` with `f`, tagging any error with its line number.
+pub fn parse_lines<T, F>(input: &str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.on_line(i + 1)))
+        .collect()
+}
+
+// Parses the whole of `text` as a number (or anything else FromStr).
+pub fn parse_num<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    text.parse().map_err(|_| {
+        ParseError::at(
+            text,
+            0,
+            format!("couldn't read {:?} as {}", text, type_name::<T>()),
+        )
+    })
+}
+
+// Parses the whitespace-separated values in `line`.
+pub fn parse_nums<T: FromStr>(line: &str) -> Result<Vec<T>, ParseError> {
+    line.split_whitespace()
+        .map(|word| {
+            let offset = word.as_ptr() as usize - line.as_ptr() as usize;
+            parse_num(word).map_err(|e| e.within(line, offset))
+        })
+        .collect()
+}
+
+// The integers in a piece of text, read by `ints` or `uints`.
+pub struct Ints<'a, T> {
+    text: &'a str,
+    pos: usize,
+    signed: bool,
+    _type: PhantomData<T>,
+}
+
+// Every integer in `text`, whatever is around them.  A '-' straight before a
+// number makes it negative, unless it follows another number ("1-3" is 1 and
+// 3, but "x=-3" is -3).  Numbers that don't fit in T are errors.
+pub fn ints<T: FromStr>(text: &str) -> Ints<'_, T> {
+    Ints {
+        text,
+        pos: 0,
+        signed: true,
+        _type: PhantomData,
+    }
+}
+
+// Like `ints`, but every '-' is just a separator.  Like `ints` it skips
+// anything that isn't a digit, so it suits text with numbers scattered
+// through it; use parse_nums where stray text should be an error.
+pub fn uints<T: FromStr>(text: &str) -> Ints<'_, T> {
+    Ints {
+        signed: false,
+        ..ints(text)
+    }
+}
+
+impl<'a, T: FromStr> Ints<'a, T> {
+    // Reads exactly N numbers.
+    pub fn array<const N: usize>(self) -> Result<[T; N], ParseError> {
+        let text = self.text;
+        let values: Vec<T> = self.collect::<Result<_, _>>()?;
+        let found = values.len();
+        values.try_into().map_err(|_| {
+            ParseError::at(text, 0, format!("expected {} numbers, found {}", N, found))
+        })
+    }
+
+    // Points an error at `start` in the text, giving the line number too if
+    // the text has more than one line.
+    fn error_at(&self, start: usize, e: ParseError) -> ParseError {
+        let line_start = self.text[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.text[start..]
+            .find('\n')
+            .map_or(self.text.len(), |i| start + i);
+        let line = self.text[line_start..line_end].trim_end_matches('\r');
+        let e = e.within(line, start - line_start);
+        if self.text.contains('\n') {
+            e.on_line(self.text[..start].matches('\n').count() + 1)
+        } else {
+            e
         }
-        if inc_text {
-            for (key, val) in TEXT_DIGITS.iter() {
-                if let Ok((rem, _)) = take_tag(remainder, *key) {
-                    if consume {
-                        return (rem, Some(*val));
-                    }
-                    if let Ok((rem, _)) = take_n(remainder, 1) {
-                        return (rem, Some(*val));
-                    } else {
-                        return ("", Some(*val));
-                    }
-                }
-            }
+    }
+}
+
+impl<'a, T: FromStr> Iterator for Ints<'a, T> {
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.text.as_bytes();
+        let digits = self.pos + bytes[self.pos..].iter().position(|b| b.is_ascii_digit())?;
+        let end = bytes[digits..]
+            .iter()
+            .position(|b| !b.is_ascii_digit())
+            .map_or(bytes.len(), |n| digits + n);
+        let mut start = digits;
+        if self.signed
+            && start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_digit())
+        {
+            start -= 1;
         }
-        if let Ok((rem, _)) = take_n(remainder, 1) {
-            remainder = rem;
+        self.pos = end;
+        Some(parse_num(&self.text[start..end]).map_err(|e| self.error_at(start, e)))
+    }
+}
+
+// A paragraph of the input: a run of lines with blank lines either side.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Block<'a> {
+    // The first line, minus its colon, if it ends in one ("Player 1:",
+    // "your ticket:", "seed-to-soil map:").
+    pub header: Option<&'a str>,
+    // The lines after the header (or all of them, if there isn't one).
+    pub lines: Vec<&'a str>,
+    // The same lines as one slice of the input, for Grid::parse and friends.
+    pub text: &'a str,
+    // The line number of lines[0] in the whole input, counting from 1.
+    pub line: usize,
+}
+
+impl<'a> Block<'a> {
+    // The line number of the header, or of the first line if there's no header.
+    pub fn start(&self) -> usize {
+        if self.header.is_some() {
+            self.line - 1
         } else {
-            break;
+            self.line
+        }
+    }
+
+    // Like parse_lines, but numbering lines as they are in the whole input.
+    pub fn parse_lines<T, F>(&self, mut f: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(&str) -> Result<T, ParseError>,
+    {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| f(line).map_err(|e| e.on_line(self.line + i)))
+            .collect()
+    }
+}
+
+// Splits `input` at blank (or all-whitespace) lines.  Several blank lines in a
+// row, and blank lines at the start or end, don't make empty blocks.  Lines
+// can end in "\n" or "\r\n".
+pub fn blocks(input: &str) -> Vec<Block<'_>> {
+    let offset = |line: &str| line.as_ptr() as usize - input.as_ptr() as usize;
+    let mut blocks = Vec::new();
+    let mut pending: Vec<&str> = Vec::new();
+    let mut start = 0;
+    // The extra blank line on the end finishes off the last block.
+    for (i, line) in input.lines().chain(std::iter::once("")).enumerate() {
+        if !line.trim().is_empty() {
+            if pending.is_empty() {
+                start = i + 1;
+            }
+            pending.push(line);
+            continue;
+        }
+        if pending.is_empty() {
+            continue;
+        }
+        let mut lines = std::mem::take(&mut pending);
+        let header = lines[0].trim_end().strip_suffix(':');
+        if header.is_some() {
+            lines.remove(0);
         }
+        let text = match (lines.first(), lines.last()) {
+            (Some(first), Some(last)) => &input[offset(first)..offset(last) + last.len()],
+            _ => "",
+        };
+        blocks.push(Block {
+            header,
+            lines,
+            text,
+            line: start + header.is_some() as usize,
+        });
+    }
+    blocks
+}
+
+// Parses each block of `input` with `f`.  Errors that don't say which line
+// they're on are put on the block's first line.
+pub fn parse_blocks<T, F>(input: &str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&Block) -> Result<T, ParseError>,
+{
+    blocks(input)
+        .iter()
+        .map(|block| {
+            f(block).map_err(|e| match e.line {
+                Some(_) => e,
+                None => e.on_line(block.start()),
+            })
+        })
+        .collect()
+}
+
+// Finds the first digit in `s`, also counting words like "one" if `inc_text`
+// is set.  Returns what's left after the digit, or (if not `consume`) after
+// just its first character, so that overlapping words like "twone" give both.
+pub fn get_next_digit(s: &str, inc_text: bool, consume: bool) -> (&str, Option<u8>) {
+    let matcher: &Matcher<u8> = if inc_text { &TEXT_DIGITS } else { &DIGITS };
+    match matcher.first(s) {
+        Some(m) if consume => (&s[m.end..], Some(*m.value)),
+        Some(m) => (&s[m.start + 1..], Some(*m.value)),
+        None => ("", None),
     }
-    return ("", None);
 }
 
 pub fn take_tag<'a>(s: &'a str, t: &'a str) -> IResult<&'a str, &'a str> {
@@ -182,3 +353,94 @@ pub fn take_card_char(s: &str) -> IResult<&str, char> {
 pub fn take_until_pattern<'a>(s: &'a str, p: &'a str) -> IResult<&'a str, &'a str> {
     take_until(p)(s)
 }
+
+#[cfg(test)]
+mod parsing_tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(parse_num::<i32>("-12"), Ok(-12));
+        assert_eq!(parse_nums::<u64>("  79 14\t55 "), Ok(vec![79, 14, 55]));
+        let err = parse_nums::<u8>("1 2 300").unwrap_err();
+        assert_eq!(err.column, 5);
+        assert_eq!(err.message, "couldn't read \"300\" as u8");
+        assert_eq!(err.text, "1 2 300");
+    }
+
+    #[test]
+    fn line_numbers() {
+        assert_eq!(parse_lines("1\n2\n", parse_num::<u8>), Ok(vec![1, 2]));
+        let err = parse_lines("1\n2\nthree 4\n", parse_nums::<u8>).unwrap_err();
+        assert_eq!(err.line, Some(3));
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: couldn't read \"three\" as u8\n  three 4\n  ^"
+        );
+    }
+
+    #[test]
+    fn extracting_ints() {
+        let found: Vec<i64> = ints("x=-3, y=12..-4 (1-3)")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(found, vec![-3, 12, -4, 1, 3]);
+        let found: Vec<u32> = uints("x=-3, y=12..-4").collect::<Result<_, _>>().unwrap();
+        assert_eq!(found, vec![3, 12, 4]);
+        assert_eq!(uints::<u8>("class: 1-3 or 5-7").array(), Ok([1, 3, 5, 7]));
+        assert_eq!(ints::<i8>("[-1,{\"a\":1}]").array(), Ok([-1, 1]));
+        assert!(ints::<i32>("no numbers here").next().is_none());
+
+        let err = uints::<u8>("a=1 b=2").array::<3>().unwrap_err();
+        assert_eq!(err.message, "expected 3 numbers, found 2");
+
+        let err = uints::<u8>("1 2 300").nth(2).unwrap().unwrap_err();
+        assert_eq!((err.line, err.column), (None, 5));
+        assert_eq!(err.message, "couldn't read \"300\" as u8");
+        let err = ints::<u32>("7\r\n8, -9\n").find_map(Result::err).unwrap();
+        assert_eq!((err.line, err.column), (Some(2), 4));
+        assert_eq!(err.text, "8, -9");
+    }
+
+    #[test]
+    fn splitting_blocks() {
+        let input = "\nPlayer 1:\n9\n2\n\n\n\nPlayer 2:\n5\n8\n\nabc\n  \ndef\nghi\n\n";
+        let found = blocks(input);
+        assert_eq!(found.len(), 4);
+        assert_eq!(found[0].header, Some("Player 1"));
+        assert_eq!(found[0].lines, vec!["9", "2"]);
+        assert_eq!((found[0].line, found[0].start()), (3, 2));
+        assert_eq!(found[1].text, "5\n8");
+        assert_eq!(found[2].header, None);
+        assert_eq!((found[2].text, found[2].line), ("abc", 12));
+        assert_eq!(found[3].lines, vec!["def", "ghi"]);
+
+        // Windows line endings split the same way.
+        let crlf = input.replace('\n', "\r\n");
+        let found_crlf = blocks(&crlf);
+        assert_eq!(found_crlf.len(), 4);
+        for (a, b) in found.iter().zip(&found_crlf) {
+            assert_eq!((a.header, &a.lines, a.line), (b.header, &b.lines, b.line));
+        }
+        assert_eq!(found_crlf[3].text, "def\r\nghi");
+        assert!(blocks("\n\n").is_empty());
+    }
+
+    #[test]
+    fn parsing_blocks() {
+        let input = "1\n2\n\n3\n\ntotal:\n4\nfive\n";
+        let err = parse_blocks(input, |block| block.parse_lines(parse_num::<u8>)).unwrap_err();
+        assert_eq!(err.line, Some(8));
+        let sums = parse_blocks("1\n2\n\n3\n", |block| {
+            Ok(block.parse_lines(parse_num::<u8>)?.iter().sum::<u8>())
+        });
+        assert_eq!(sums, Ok(vec![3, 3]));
+
+        let err = parse_blocks(input, |block| match block.header {
+            Some(_) => Err(ParseError::at(block.text, 0, "no headers here")),
+            None => Ok(()),
+        })
+        .unwrap_err();
+        assert_eq!(err.line, Some(6));
+    }
+}
@@ -0,0 +1,380 @@
+use crate::parsing::{parse_lines, parse_num};
+use std::str::FromStr;
+
+pub use crate::parsing::ParseError;
+
+#[derive(Clone, Debug)]
+enum Piece {
+    Literal(String),
+    // An empty list of choices matches anything up to the next literal.
+    Field { name: String, choices: Vec<String> },
+}
+
+// A line format such as
+//
+//   "{name} would {sign:gain|lose} {n} happiness units by sitting next to {other}."
+//
+// Each `{...}` is a field: `{name}` captures text, `{name:a|b}` must be one of
+// the listed words, and `{a|b}` is a choice named after itself.  Fields can be
+// left unnamed (`{}`) and read by position instead.  `{{` and `}}` are literal
+// braces.  A free-text field runs up to the next piece of literal text, so two
+// of them can't be next to each other.
+#[derive(Clone, Debug)]
+pub struct Format {
+    pieces: Vec<Piece>,
+}
+
+impl Format {
+    // Panics if `spec` is malformed, since that's a bug rather than bad input.
+    pub fn new(spec: &str) -> Format {
+        let mut pieces: Vec<Piece> = Vec::new();
+        let mut literal = String::new();
+        let mut chars = spec.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let mut field = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => field.push(c),
+                            None => panic!("unclosed field in format {:?}", spec),
+                        }
+                    }
+                    if !literal.is_empty() {
+                        pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                    }
+                    if let Some(Piece::Field { choices, .. }) = pieces.last() {
+                        assert!(
+                            !choices.is_empty(),
+                            "fields need text between them in format {:?}",
+                            spec
+                        );
+                    }
+                    pieces.push(Format::field(&field));
+                }
+                '}' => panic!("unmatched '}}' in format {:?}", spec),
+                _ => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            pieces.push(Piece::Literal(literal));
+        }
+        Format { pieces }
+    }
+
+    fn field(spec: &str) -> Piece {
+        let (name, choices) = match spec.split_once(':') {
+            Some((name, choices)) => (name, choices),
+            None if spec.contains('|') => (spec, spec),
+            None => (spec, ""),
+        };
+        let mut choices: Vec<String> = choices
+            .split('|')
+            .filter(|c| !c.is_empty())
+            .map(|c| c.to_string())
+            .collect();
+        // Longest first, so that one choice being a prefix of another is fine.
+        choices.sort_by_key(|c| std::cmp::Reverse(c.len()));
+        Piece::Field {
+            name: name.to_string(),
+            choices,
+        }
+    }
+
+    pub fn parse<'a>(&'a self, line: &'a str) -> Result<Record<'a>, ParseError> {
+        let mut values: Vec<(usize, &str)> = Vec::new();
+        let mut pos = 0;
+        for (i, piece) in self.pieces.iter().enumerate() {
+            let rest = &line[pos..];
+            match piece {
+                Piece::Literal(text) => {
+                    if !rest.starts_with(text.as_str()) {
+                        return Err(ParseError::at(
+                            line,
+                            pos,
+                            format!("expected {:?}, found {:?}", text, preview(rest)),
+                        ));
+                    }
+                    pos += text.len();
+                }
+                Piece::Field { choices, .. } if !choices.is_empty() => {
+                    let Some(choice) = choices.iter().find(|c| rest.starts_with(c.as_str())) else {
+                        return Err(ParseError::at(
+                            line,
+                            pos,
+                            format!(
+                                "expected one of {}, found {:?}",
+                                choices.join("|"),
+                                preview(rest)
+                            ),
+                        ));
+                    };
+                    values.push((pos, &rest[..choice.len()]));
+                    pos += choice.len();
+                }
+                Piece::Field { .. } => {
+                    let len = match self.pieces.get(i + 1) {
+                        Some(Piece::Literal(next)) => {
+                            rest.find(next.as_str()).ok_or_else(|| {
+                                ParseError::at(line, pos, format!("expected {:?} after this", next))
+                            })?
+                        }
+                        _ => rest.len(),
+                    };
+                    if len == 0 {
+                        return Err(ParseError::at(line, pos, "expected a value"));
+                    }
+                    values.push((pos, &rest[..len]));
+                    pos += len;
+                }
+            }
+        }
+        if pos < line.len() {
+            return Err(ParseError::at(
+                line,
+                pos,
+                format!("unexpected {:?} at the end", &line[pos..]),
+            ));
+        }
+        Ok(Record {
+            format: self,
+            line,
+            values,
+        })
+    }
+
+    // Parses `line` straight into a tuple, or a struct set up with
+    // `impl_from_record!`.
+    pub fn extract<T: FromRecord>(&self, line: &str) -> Result<T, ParseError> {
+        T::from_record(&self.parse(line)?)
+    }
+
+    // Extracts every line of `input`, with errors giving the line number.
+    pub fn extract_all<T: FromRecord>(&self, input: &str) -> Result<Vec<T>, ParseError> {
+        parse_lines(input, |line| self.extract(line))
+    }
+}
+
+// The start of `text`, for error messages.
+fn preview(text: &str) -> String {
+    let word: String = text
+        .chars()
+        .take_while(|c| !c.is_whitespace())
+        .take(20)
+        .collect();
+    if word.is_empty() {
+        text.chars().take(1).collect()
+    } else {
+        word
+    }
+}
+
+// The fields pulled out of one line by a Format.
+#[derive(Clone, Debug)]
+pub struct Record<'a> {
+    format: &'a Format,
+    line: &'a str,
+    // The byte offset of each field's text, and the text itself.
+    values: Vec<(usize, &'a str)>,
+}
+
+impl<'a> Record<'a> {
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    fn index_of(&self, name: &str) -> usize {
+        self.format
+            .pieces
+            .iter()
+            .filter_map(|p| match p {
+                Piece::Field { name, .. } => Some(name),
+                Piece::Literal(_) => None,
+            })
+            .position(|n| n == name)
+            .unwrap_or_else(|| panic!("the format has no field called {:?}", name))
+    }
+
+    // The raw text of a field.  Panics if there's no such field.
+    pub fn str(&self, name: &str) -> &'a str {
+        self.values[self.index_of(name)].1
+    }
+
+    // Parses the named field.  Panics if there's no such field.
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<T, ParseError> {
+        self.at(self.index_of(name))
+    }
+
+    // Parses the `index`th field (counting from 0).
+    pub fn at<T: FromStr>(&self, index: usize) -> Result<T, ParseError> {
+        let (offset, text) = self.values[index];
+        parse_num(text).map_err(|e| e.within(self.line, offset))
+    }
+}
+
+pub trait FromRecord: Sized {
+    fn from_record(record: &Record) -> Result<Self, ParseError>;
+}
+
+// Tuples take the fields in order.
+macro_rules! tuple_from_record {
+    ($($t:ident $i:tt),*) => {
+        impl<$($t: FromStr),*> FromRecord for ($($t,)*) {
+            fn from_record(record: &Record) -> Result<Self, ParseError> {
+                Ok(($(record.at::<$t>($i)?,)*))
+            }
+        }
+    };
+}
+
+tuple_from_record!(A 0);
+tuple_from_record!(A 0, B 1);
+tuple_from_record!(A 0, B 1, C 2);
+tuple_from_record!(A 0, B 1, C 2, D 3);
+tuple_from_record!(A 0, B 1, C 2, D 3, E 4);
+tuple_from_record!(A 0, B 1, C 2, D 3, E 4, F 5);
+tuple_from_record!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple_from_record!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+// Implements FromRecord for a struct by reading each listed field from the
+// format field of the same name:
+//
+//   struct Move { count: usize, from: usize, to: usize }
+//   impl_from_record!(Move { count, from, to });
+#[macro_export]
+macro_rules! impl_from_record {
+    ($name:ident { $($field:ident),* $(,)? }) => {
+        impl $crate::record::FromRecord for $name {
+            fn from_record(
+                record: &$crate::record::Record,
+            ) -> Result<Self, $crate::record::ParseError> {
+                Ok($name {
+                    $($field: record.get(stringify!($field))?,)*
+                })
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod record_tests {
+    use super::*;
+
+    const SEATING: &str =
+        "{name} would {sign:gain|lose} {n} happiness units by sitting next to {other}.";
+
+    #[derive(Debug, PartialEq)]
+    struct Preference {
+        name: String,
+        sign: String,
+        n: u32,
+        other: String,
+    }
+
+    impl_from_record!(Preference {
+        name,
+        sign,
+        n,
+        other
+    });
+
+    #[test]
+    fn fields() {
+        let format = Format::new(SEATING);
+        let record = format
+            .parse("Alice would lose 79 happiness units by sitting next to Carol.")
+            .unwrap();
+        assert_eq!(record.len(), 4);
+        assert_eq!(record.str("name"), "Alice");
+        assert_eq!(record.str("sign"), "lose");
+        assert_eq!(record.get::<i64>("n"), Ok(79));
+        assert_eq!(record.at::<String>(3), Ok("Carol".to_string()));
+    }
+
+    #[test]
+    fn structs_and_tuples() {
+        let preference: Preference = Format::new(SEATING)
+            .extract("Bob would gain 83 happiness units by sitting next to Alice.")
+            .unwrap();
+        assert_eq!(
+            preference,
+            Preference {
+                name: "Bob".to_string(),
+                sign: "gain".to_string(),
+                n: 83,
+                other: "Alice".to_string(),
+            }
+        );
+
+        let segment = Format::new("{},{} -> {},{}");
+        assert_eq!(segment.extract("0,9 -> 5,9"), Ok((0, 9, 5, 9)));
+        assert_eq!(
+            segment.extract_all::<(u8, u8, u8, u8)>("0,9 -> 5,9\n8,0 -> 0,8\n"),
+            Ok(vec![(0, 9, 5, 9), (8, 0, 0, 8)])
+        );
+        let err = segment
+            .extract_all::<(u8, u8, u8, u8)>("0,9 -> 5,9\n8,0 => 0,8\n")
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), 3));
+        let braces = Format::new("{{{}}} {on|off}");
+        assert_eq!(braces.extract("{x} off"), Ok(('x', "off".to_string())));
+    }
+
+    #[test]
+    fn errors() {
+        let format = Format::new(SEATING);
+        let err = format
+            .parse("Alice would win 79 happiness units by sitting next to Carol.")
+            .unwrap_err();
+        assert_eq!(err.column, 13);
+        assert_eq!(err.message, "expected one of gain|lose, found \"win\"");
+        assert_eq!(
+            err.to_string(),
+            "column 13: expected one of gain|lose, found \"win\"\n  \
+             Alice would win 79 happiness units by sitting next to Carol.\n  \
+             \x20           ^"
+        );
+
+        let err = format
+            .extract::<Preference>(
+                "Alice would gain lots happiness units by sitting next to Carol.",
+            )
+            .unwrap_err();
+        assert_eq!(err.column, 18);
+        assert_eq!(err.message, "couldn't read \"lots\" as u32");
+
+        let err = format.parse("Alice would gain 5").unwrap_err();
+        assert_eq!(err.column, 18);
+        let err = format
+            .parse("Alice would gain 5 happiness units by sitting next to Carol!")
+            .unwrap_err();
+        assert_eq!(err.column, 55);
+        // The last field takes the rest of the line.
+        let err = Format::new("{}-{}")
+            .extract::<(i32, i32)>("1-2-3")
+            .unwrap_err();
+        assert_eq!(
+            (err.column, err.message.as_str()),
+            (3, "couldn't read \"2-3\" as i32")
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn adjacent_fields() {
+        Format::new("{a}{b}");
+    }
+}
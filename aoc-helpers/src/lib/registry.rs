@@ -0,0 +1,118 @@
+use crate::runner::AocSolution;
+use std::collections::BTreeMap;
+
+// Every solution for a single year, keyed by day.  Days that haven't been
+// solved yet are simply never added.
+pub struct Registry {
+    year: u32,
+    solutions: BTreeMap<u32, Box<dyn AocSolution>>,
+}
+
+impl Registry {
+    pub fn new(year: u32) -> Registry {
+        Registry {
+            year,
+            solutions: BTreeMap::new(),
+        }
+    }
+
+    pub fn add<T: AocSolution + 'static>(&mut self, solution: T) {
+        assert_eq!(
+            solution.year(),
+            self.year,
+            "day {} belongs to {}, not {}",
+            solution.day(),
+            solution.year(),
+            self.year
+        );
+        self.solutions.insert(solution.day(), Box::new(solution));
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    pub fn get(&self, day: u32) -> Option<&dyn AocSolution> {
+        self.solutions.get(&day).map(|s| s.as_ref())
+    }
+
+    pub fn is_implemented(&self, day: u32) -> bool {
+        self.solutions.contains_key(&day)
+    }
+
+    // Registered solutions in day order.
+    pub fn solutions(&self) -> impl Iterator<Item = &dyn AocSolution> {
+        self.solutions.values().map(|s| s.as_ref())
+    }
+
+    // One line per year, e.g. "2021: 1-3 5-17 (stubbed: 4 18-25)".
+    pub fn summary(&self) -> String {
+        let implemented: Vec<u32> = (1..=25).filter(|d| self.is_implemented(*d)).collect();
+        let stubbed: Vec<u32> = (1..=25).filter(|d| !self.is_implemented(*d)).collect();
+        let mut line = format!("{}: {}", self.year, format_ranges(&implemented));
+        if !stubbed.is_empty() {
+            line += &format!(" (stubbed: {})", format_ranges(&stubbed));
+        }
+        line
+    }
+}
+
+// Collapses a sorted list of days into runs like "1-3 5 7-9".
+fn format_ranges(days: &[u32]) -> String {
+    if days.is_empty() {
+        return "none".to_string();
+    }
+    let mut ranges: Vec<String> = Vec::new();
+    let mut start = days[0];
+    let mut prev = days[0];
+    for &day in days.iter().skip(1).chain(std::iter::once(&u32::MAX)) {
+        if day == prev + 1 {
+            prev = day;
+            continue;
+        }
+        if start == prev {
+            ranges.push(start.to_string());
+        } else {
+            ranges.push(format!("{}-{}", start, prev));
+        }
+        start = day;
+        prev = day;
+    }
+    ranges.join(" ")
+}
+
+#[cfg(test)]
+mod registry_tests {
+    use super::*;
+    use crate::runner::Answer;
+
+    struct Fake(u32);
+
+    impl AocSolution for Fake {
+        fn year(&self) -> u32 {
+            2021
+        }
+        fn day(&self) -> u32 {
+            self.0
+        }
+        fn part_one(&self, _input: &str) -> Answer {
+            Answer::Number(1)
+        }
+        fn part_two(&self, _input: &str) -> Answer {
+            Answer::Number(2)
+        }
+    }
+
+    #[test]
+    fn summary() {
+        let mut registry = Registry::new(2021);
+        for day in (1..=17).filter(|d| *d != 4) {
+            registry.add(Fake(day));
+        }
+        assert!(registry.is_implemented(3));
+        assert!(!registry.is_implemented(4));
+        assert_eq!(registry.get(17).unwrap().day(), 17);
+        assert_eq!(registry.solutions().count(), 16);
+        assert_eq!(registry.summary(), "2021: 1-3 5-17 (stubbed: 4 18-25)");
+    }
+}
@@ -0,0 +1,369 @@
+use crate::answers::Answers;
+use crate::bench::{format_duration, time};
+use crate::runner::{solve, AocSolution};
+use std::any::Any;
+use std::fmt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    // Nothing has been recorded for this part yet.
+    Missing,
+    // The solution doesn't have an answer for this part yet.
+    Unimplemented,
+    // The puzzle input couldn't be loaded, so nothing was run.
+    NoInput(String),
+    // The solution couldn't parse the input.
+    BadInput(String),
+    // The recorded answers couldn't be loaded, so there was nothing to check
+    // against.
+    BadAnswers(String),
+    // The solution panicked.
+    Panicked(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { .. } => write!(f, "FAIL"),
+            Status::Missing => write!(f, "missing"),
+            Status::Unimplemented => write!(f, "stub"),
+            Status::NoInput(_) => write!(f, "no input"),
+            Status::BadInput(_) => write!(f, "bad input"),
+            Status::BadAnswers(_) => write!(f, "bad file"),
+            Status::Panicked(_) => write!(f, "panicked"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Outcome {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub status: Status,
+    pub actual: Option<String>,
+    pub elapsed: Option<Duration>,
+}
+
+// Runs both parts of `solution` and compares them with the recorded answers.
+// Parsing isn't included in the times, same as when running a day normally.
+// A panic only fails the part it happened in, so one broken day can't stop a
+// whole run of checks.
+pub fn check<T: AocSolution + ?Sized>(
+    solution: &T,
+    input: &str,
+    answers: &Answers,
+) -> Vec<Outcome> {
+    let (year, day) = (solution.year(), solution.day());
+    let prepared = match catch_unwind(AssertUnwindSafe(|| solution.prepare(input))) {
+        Ok(Ok(prepared)) => prepared,
+        Ok(Err(e)) => return unrun(year, day, Status::BadInput(e.to_string())),
+        Err(payload) => return unrun(year, day, Status::Panicked(panic_message(payload))),
+    };
+    (1..=2)
+        .map(|part| {
+            let (actual, elapsed) = time(|| {
+                catch_unwind(AssertUnwindSafe(|| {
+                    solve(solution, prepared.as_deref(), part, input)
+                }))
+            });
+            let actual = match actual {
+                Ok(actual) => actual,
+                Err(payload) => {
+                    return Outcome {
+                        year,
+                        day,
+                        part,
+                        status: Status::Panicked(panic_message(payload)),
+                        actual: None,
+                        elapsed: Some(elapsed),
+                    }
+                }
+            };
+            let actual = actual.is_implemented().then(|| actual.to_string());
+            let status = match (&actual, answers.get(solution.day(), part)) {
+                (None, _) => Status::Unimplemented,
+                (_, None) => Status::Missing,
+                (Some(actual), Some(expected)) if expected == actual => Status::Pass,
+                (_, Some(expected)) => Status::Fail {
+                    expected: expected.to_string(),
+                },
+            };
+            Outcome {
+                year: solution.year(),
+                day: solution.day(),
+                part,
+                status,
+                actual,
+                elapsed: Some(elapsed),
+            }
+        })
+        .collect()
+}
+
+// Both parts of a day that couldn't be run at all.
+fn unrun(year: u32, day: u32, status: Status) -> Vec<Outcome> {
+    (1..=2)
+        .map(|part| Outcome {
+            year,
+            day,
+            part,
+            status: status.clone(),
+            actual: None,
+            elapsed: None,
+        })
+        .collect()
+}
+
+pub fn no_input(year: u32, day: u32, reason: &str) -> Vec<Outcome> {
+    unrun(year, day, Status::NoInput(reason.to_string()))
+}
+
+pub fn bad_answers(year: u32, day: u32, reason: &str) -> Vec<Outcome> {
+    unrun(year, day, Status::BadAnswers(reason.to_string()))
+}
+
+// panic! gives a &str or a String, depending on whether it had arguments.
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+pub fn all_passed(outcomes: &[Outcome]) -> bool {
+    !outcomes.iter().any(|o| {
+        matches!(
+            o.status,
+            Status::Fail { .. }
+                | Status::NoInput(_)
+                | Status::BadInput(_)
+                | Status::BadAnswers(_)
+                | Status::Panicked(_)
+        )
+    })
+}
+
+pub fn format_table(outcomes: &[Outcome]) -> String {
+    let mut table = format!(
+        "{:<6}{:>4}{:>6}  {:<9}{:>10}  {}\n",
+        "year", "day", "part", "result", "time", "details"
+    );
+    for o in outcomes {
+        let details = match &o.status {
+            Status::Pass => one_line(o.actual.as_deref().unwrap_or("")),
+            Status::Fail { expected } => format!(
+                "got {}, expected {}",
+                one_line(o.actual.as_deref().unwrap_or("")),
+                one_line(expected)
+            ),
+            Status::Missing => format!("got {}", one_line(o.actual.as_deref().unwrap_or(""))),
+            Status::Unimplemented => String::new(),
+            Status::NoInput(reason) => reason.clone(),
+            // Just the first line: the rest shows where in the input it was.
+            Status::BadInput(error) => error.lines().next().unwrap_or("").to_string(),
+            Status::BadAnswers(reason) | Status::Panicked(reason) => one_line(reason),
+        };
+        let elapsed = o.elapsed.map(format_duration).unwrap_or_default();
+        table += &format!(
+            "{:<6}{:>4}{:>6}  {:<9}{:>10}  {}\n",
+            o.year,
+            o.day,
+            o.part,
+            o.status.to_string(),
+            elapsed,
+            details
+        );
+    }
+    let count = |f: fn(&Status) -> bool| outcomes.iter().filter(|o| f(&o.status)).count();
+    table += &format!(
+        "{} passed, {} failed, {} missing, {} stubbed, {} without input\n",
+        count(|s| *s == Status::Pass),
+        count(|s| matches!(
+            s,
+            Status::Fail { .. } | Status::BadInput(_) | Status::BadAnswers(_) | Status::Panicked(_)
+        )),
+        count(|s| *s == Status::Missing),
+        count(|s| *s == Status::Unimplemented),
+        count(|s| matches!(s, Status::NoInput(_)))
+    );
+    table
+}
+
+// Multi-line answers (e.g. letters drawn in ASCII art) would break the table.
+fn one_line(answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("{:?}", answer)
+    } else {
+        answer.to_string()
+    }
+}
+
+#[cfg(test)]
+mod regression_tests {
+    use super::*;
+    use crate::parsing::{parse_num, ParseError};
+    use crate::runner::{Answer, ParsedSolution};
+    use std::env;
+    use std::fs;
+
+    struct Doubler;
+
+    impl AocSolution for Doubler {
+        fn year(&self) -> u32 {
+            2020
+        }
+        fn day(&self) -> u32 {
+            3
+        }
+        fn part_one(&self, input: &str) -> Answer {
+            (input.trim().parse::<u32>().unwrap() * 2).into()
+        }
+        fn part_two(&self, input: &str) -> Answer {
+            (input.trim().parse::<u32>().unwrap() * 3).into()
+        }
+    }
+
+    struct Stub;
+
+    impl AocSolution for Stub {
+        fn year(&self) -> u32 {
+            2020
+        }
+        fn day(&self) -> u32 {
+            4
+        }
+        fn part_one(&self, _input: &str) -> Answer {
+            Answer::Unimplemented
+        }
+        fn part_two(&self, _input: &str) -> Answer {
+            Answer::Unimplemented
+        }
+    }
+
+    struct Crasher;
+
+    impl AocSolution for Crasher {
+        fn year(&self) -> u32 {
+            2020
+        }
+        fn day(&self) -> u32 {
+            6
+        }
+        fn part_one(&self, input: &str) -> Answer {
+            panic!("can't handle {}", input)
+        }
+        fn part_two(&self, _input: &str) -> Answer {
+            7.into()
+        }
+    }
+
+    struct Picky;
+
+    impl ParsedSolution for Picky {
+        type Parsed = u32;
+
+        fn year(&self) -> u32 {
+            2020
+        }
+        fn day(&self) -> u32 {
+            5
+        }
+        fn parse(&self, input: &str) -> Result<u32, ParseError> {
+            parse_num(input.trim())
+        }
+        fn solve_part_one(&self, n: &u32) -> Answer {
+            (*n).into()
+        }
+        fn solve_part_two(&self, n: &u32) -> Answer {
+            (*n).into()
+        }
+    }
+
+    #[test]
+    fn pass_fail_missing() {
+        let dir = env::temp_dir().join(format!("aoc-regression-{}", std::process::id()));
+        let mut answers = Answers::load(Answers::path_for(&dir, 2020)).unwrap();
+        answers.set(3, 1, "42");
+
+        let outcomes = check(&Doubler, "21", &answers);
+        assert_eq!(outcomes[0].status, Status::Pass);
+        assert_eq!(outcomes[1].status, Status::Missing);
+        assert!(all_passed(&outcomes));
+
+        answers.set(3, 2, "64");
+        let outcomes = check(&Doubler, "21", &answers);
+        assert_eq!(
+            outcomes[1].status,
+            Status::Fail {
+                expected: "64".to_string()
+            }
+        );
+        assert!(!all_passed(&outcomes));
+        let table = format_table(&outcomes);
+        assert!(table.contains("got 63, expected 64"));
+        assert!(table.ends_with("1 passed, 1 failed, 0 missing, 0 stubbed, 0 without input\n"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn stubs_are_not_failures() {
+        let dir = env::temp_dir().join(format!("aoc-regression-stub-{}", std::process::id()));
+        let mut answers = Answers::load(Answers::path_for(&dir, 2020)).unwrap();
+        answers.set(4, 1, "7");
+
+        let outcomes = check(&Stub, "", &answers);
+        assert_eq!(outcomes[0].status, Status::Unimplemented);
+        assert_eq!(outcomes[1].status, Status::Unimplemented);
+        assert!(all_passed(&outcomes));
+    }
+
+    #[test]
+    fn bad_input_fails() {
+        let dir = env::temp_dir().join(format!("aoc-regression-bad-{}", std::process::id()));
+        let answers = Answers::load(Answers::path_for(&dir, 2020)).unwrap();
+
+        let outcomes = check(&Picky, "12", &answers);
+        assert_eq!(outcomes[0].status, Status::Missing);
+        let outcomes = check(&Picky, "twelve", &answers);
+        assert!(matches!(outcomes[0].status, Status::BadInput(_)));
+        assert!(!all_passed(&outcomes));
+        let table = format_table(&outcomes);
+        assert!(table.contains("bad input"));
+        assert!(table.contains("column 1: couldn't read \"twelve\" as u32\n"));
+    }
+
+    #[test]
+    fn panics_and_bad_answers_fail() {
+        let dir = env::temp_dir().join(format!("aoc-regression-panic-{}", std::process::id()));
+        let mut answers = Answers::load(Answers::path_for(&dir, 2020)).unwrap();
+        answers.set(6, 2, "7");
+
+        let outcomes = check(&Crasher, "this", &answers);
+        assert_eq!(
+            outcomes[0].status,
+            Status::Panicked("can't handle this".to_string())
+        );
+        // The other part still runs.
+        assert_eq!(outcomes[1].status, Status::Pass);
+        assert!(!all_passed(&outcomes));
+        let table = format_table(&outcomes);
+        assert!(table.contains("panicked"));
+        assert!(table.ends_with("1 passed, 1 failed, 0 missing, 0 stubbed, 0 without input\n"));
+
+        let outcomes = bad_answers(2020, 6, "failed to load answers: line 3");
+        assert!(!all_passed(&outcomes));
+        let table = format_table(&outcomes);
+        assert!(table.contains("bad file"));
+        assert!(table.ends_with("0 passed, 2 failed, 0 missing, 0 stubbed, 0 without input\n"));
+    }
+}
@@ -1,85 +1,305 @@
-use isahc::{prelude::*, send, Request};
-use std::io::{self, Write};
+use crate::answers::Answers;
+use crate::bench::{bench, format_duration, time};
+use crate::client::AocClient;
+use crate::ledger::{Ledger, Refusal};
+use crate::options::{InputSource, RunOptions, USAGE};
+use crate::parsing::ParseError;
+use crate::session::{find_session, SessionError};
+use crate::submit::SubmitResult;
+use std::env;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::process;
 
-const SESSION_COOKIE: &str = include_str!("session.cookie");
+pub use crate::answer::Answer;
 
 pub trait AocSolution {
     fn year(&self) -> u32;
     fn day(&self) -> u32;
-    fn part_one(&self, input: &str) -> String;
-    fn part_two(&self, input: &str) -> String;
+    fn part_one(&self, input: &str) -> Answer;
+    fn part_two(&self, input: &str) -> Answer;
+
+    // Solutions with a separate parse step (see ParsedSolution) parse here so
+    // that both parts can share the result.
+    fn prepare<'a>(&'a self, _input: &str) -> Result<Option<Box<dyn Prepared + 'a>>, ParseError> {
+        Ok(None)
+    }
+}
+
+// For days where both parts start by parsing the whole input the same way.
+// Implementing this instead of AocSolution lets the runner parse once, time it
+// on its own, and hand the result to both parts.  A parse error is reported by
+// the runner instead of running either part.
+pub trait ParsedSolution {
+    type Parsed: 'static;
+
+    fn year(&self) -> u32;
+    fn day(&self) -> u32;
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError>;
+    fn solve_part_one(&self, parsed: &Self::Parsed) -> Answer;
+    fn solve_part_two(&self, parsed: &Self::Parsed) -> Answer;
+}
+
+// A solution paired with its already parsed input.
+pub trait Prepared {
+    fn part_one(&self) -> Answer;
+    fn part_two(&self) -> Answer;
+}
+
+impl<T: ParsedSolution> AocSolution for T {
+    fn year(&self) -> u32 {
+        ParsedSolution::year(self)
+    }
+    fn day(&self) -> u32 {
+        ParsedSolution::day(self)
+    }
+    // Called directly (as tests do), a bad input can only panic.
+    fn part_one(&self, input: &str) -> Answer {
+        self.solve_part_one(&parse_or_panic(self, input))
+    }
+    fn part_two(&self, input: &str) -> Answer {
+        self.solve_part_two(&parse_or_panic(self, input))
+    }
+    fn prepare<'a>(&'a self, input: &str) -> Result<Option<Box<dyn Prepared + 'a>>, ParseError> {
+        Ok(Some(Box::new(WithParsed {
+            solution: self,
+            parsed: self.parse(input)?,
+        })))
+    }
+}
+
+fn parse_or_panic<T: ParsedSolution>(solution: &T, input: &str) -> T::Parsed {
+    solution
+        .parse(input)
+        .unwrap_or_else(|e| panic!("couldn't parse the input:\n{}", e))
+}
+
+struct WithParsed<'a, T: ParsedSolution> {
+    solution: &'a T,
+    parsed: T::Parsed,
+}
+
+impl<T: ParsedSolution> Prepared for WithParsed<'_, T> {
+    fn part_one(&self) -> Answer {
+        self.solution.solve_part_one(&self.parsed)
+    }
+    fn part_two(&self) -> Answer {
+        self.solution.solve_part_two(&self.parsed)
+    }
+}
+
+pub fn run<T: AocSolution + ?Sized>(solution: &T) {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = RunOptions::parse(&args).unwrap_or_else(|e| {
+        eprintln!("error: {}\n\n{}", e, USAGE);
+        process::exit(2);
+    });
+    if options.help {
+        println!("{}", USAGE);
+        return;
+    }
+    if let Err(e) = run_with_options(solution, &options) {
+        exit_with_error("failed to get puzzle input", e);
+    }
 }
 
-pub fn run<T: AocSolution>(solution: &T) {
+// Only fails if the options don't make sense or the input couldn't be loaded,
+// so that callers running several days in a row can carry on with the next one.
+pub fn run_with_options<T: AocSolution + ?Sized>(
+    solution: &T,
+    options: &RunOptions,
+) -> io::Result<()> {
+    options
+        .validate()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let interactive = options.is_interactive();
+
     println!(
         "Running AoC {} day {} solution...",
         solution.year(),
         solution.day()
     );
-    let input = get_input(solution.year(), solution.day());
-    if prompt_for_part(1) {
-        let result = solution.part_one(&input);
-        println!("{}", result);
-        if prompt_to_submit() {
-            println!(
-                "{}",
-                submit_answer(solution.year(), solution.day(), 1, &result)
-            );
+    let input = match options.input() {
+        InputSource::Puzzle => try_get_input(solution.year(), solution.day(), options.refresh)?,
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            input
+        }
+        InputSource::File(path) => fs::read_to_string(&path).map_err(|e| {
+            io::Error::new(e.kind(), format!("couldn't read {}: {}", path.display(), e))
+        })?,
+    };
+    let (prepared, parse_elapsed) = time(|| solution.prepare(&input));
+    let prepared = match prepared {
+        Ok(prepared) => prepared,
+        Err(e) => {
+            println!("Couldn't parse the input:\n{}", e);
+            return Ok(());
+        }
+    };
+    let prepared = prepared.as_deref();
+    if let Some(runs) = options.bench {
+        if prepared.is_some() {
+            let stats = bench(runs, || solution.prepare(&input)).unwrap();
+            println!("Parse: {}", stats);
+        }
+        for part in 1..=2 {
+            if !options.parts().includes(part) {
+                continue;
+            }
+            let stats = bench(runs, || solve(solution, prepared, part, &input)).unwrap();
+            println!("Part {}: {}", part, stats);
         }
+        return Ok(());
     }
-    if prompt_for_part(2) {
-        let result = solution.part_two(&input);
+    if prepared.is_some() {
+        println!("(parsing took {})", format_duration(parse_elapsed));
+    }
+    let mut can_submit = true;
+    for part in 1..=2 {
+        let wanted = if interactive {
+            prompt_for_part(part)
+        } else {
+            options.parts().includes(part)
+        };
+        if !wanted {
+            continue;
+        }
+        let (result, elapsed) = time(|| solve(solution, prepared, part, &input));
+        if !result.is_implemented() {
+            println!("Part {} isn't implemented yet.", part);
+            can_submit = false;
+            continue;
+        }
+        if let Answer::Art(_) = result {
+            println!();
+        }
         println!("{}", result);
-        if prompt_to_submit() {
-            println!(
-                "{}",
-                submit_answer(solution.year(), solution.day(), 2, &result)
-            );
-        }
-    }
-}
-
-fn get_input(year: u32, day: u32) -> String {
-    let mut response = send(
-        Request::get(format!(
-            "https://adventofcode.com/{}/day/{}/input",
-            year, day
-        ))
-        .header("cookie", format!("session={}", SESSION_COOKIE))
-        .body(())
-        .unwrap(),
-    )
-    .unwrap();
-
-    response.text().unwrap()
-}
-
-fn submit_answer(year: u32, day: u32, part: u32, answer: &str) -> String {
-    let mut response = send(
-        Request::post(format!(
-            "https://adventofcode.com/{}/day/{}/answer",
-            year, day
-        ))
-        .header("cookie", format!("session={}", SESSION_COOKIE))
-        .body(format!("level={}&answer={}", part, answer))
-        .unwrap(),
-    )
-    .unwrap();
-
-    response
-        .text()
-        .unwrap()
-        .split("<main>")
-        .skip(1)
-        .next()
-        .unwrap()
-        .split("</main>")
-        .next()
-        .unwrap()
-        .to_string()
-}
-
-fn prompt_for_part(part: u8) -> bool {
+        println!("(part {} took {})", part, format_duration(elapsed));
+        let submit = if interactive {
+            prompt_to_submit()
+        } else {
+            options.submit()
+        };
+        if !submit {
+            continue;
+        }
+        match result.submission() {
+            None if matches!(result, Answer::Art(_)) => {
+                println!("Not submitting a drawing; read it and submit the letters by hand.")
+            }
+            None => println!("Not submitting an empty answer."),
+            Some(_) if !can_submit => println!(
+                "Not submitting part {}; part {} wasn't accepted.",
+                part,
+                part - 1
+            ),
+            // Part two can't be accepted before part one, and submitting again
+            // while rate limited only restarts the timer.
+            Some(answer) => {
+                can_submit = submit_checked(solution.year(), solution.day(), part, &answer)
+            }
+        }
+    }
+    Ok(())
+}
+
+// Uses the parsed input when there is one rather than parsing all over again.
+pub(crate) fn solve<T: AocSolution + ?Sized>(
+    solution: &T,
+    prepared: Option<&dyn Prepared>,
+    part: u32,
+    input: &str,
+) -> Answer {
+    match (prepared, part) {
+        (Some(prepared), 1) => prepared.part_one(),
+        (Some(prepared), _) => prepared.part_two(),
+        (None, 1) => solution.part_one(input),
+        (None, _) => solution.part_two(input),
+    }
+}
+
+// A missing session token isn't fatal here: cached inputs don't need one, and
+// the client reports a clear error if it ends up having to go to the network.
+fn client() -> AocClient {
+    let args: Vec<String> = env::args().collect();
+    match find_session(&args) {
+        Ok(session) => AocClient::new().with_session(&session),
+        Err(SessionError::Missing { .. }) => AocClient::new(),
+        Err(e) => {
+            eprintln!("warning: {}", e);
+            AocClient::new()
+        }
+    }
+}
+
+fn exit_with_error<E: std::fmt::Display>(context: &str, e: E) -> ! {
+    eprintln!("error: {}: {}", context, e);
+    process::exit(1);
+}
+
+// Cached inputs can be re-downloaded by setting AOC_REFRESH=1 (or passing
+// --refresh to `run`).
+pub fn get_input(year: u32, day: u32) -> String {
+    try_get_input(year, day, false)
+        .unwrap_or_else(|e| exit_with_error("failed to get puzzle input", e))
+}
+
+pub fn try_get_input(year: u32, day: u32, refresh: bool) -> io::Result<String> {
+    let refresh = refresh || env::var("AOC_REFRESH").is_ok_and(|v| v == "1" || v == "true");
+    client().get_input(year, day, refresh)
+}
+
+// Where inputs, submissions and recorded answers are kept.
+pub fn cache_dir() -> PathBuf {
+    client().cache_dir().to_path_buf()
+}
+
+pub fn submit_answer(year: u32, day: u32, part: u32, answer: &str) -> SubmitResult {
+    let html = client()
+        .post_answer(year, day, part, answer)
+        .unwrap_or_else(|e| exit_with_error("failed to submit answer", e));
+    SubmitResult::from_html(&html)
+}
+
+// Consults the submission ledger before posting so known-bad answers never
+// reach the site, then records the verdict.  Returns whether the part is solved.
+fn submit_checked(year: u32, day: u32, part: u32, answer: &str) -> bool {
+    let path = Ledger::path_for(client().cache_dir(), year);
+    let mut ledger = Ledger::load(&path)
+        .unwrap_or_else(|e| exit_with_error("failed to load submission ledger", e));
+    if let Err(refusal) = ledger.check(day, part, answer) {
+        println!("Not submitting: {}.", refusal);
+        return matches!(refusal, Refusal::AlreadySolved { .. });
+    }
+    let verdict = submit_answer(year, day, part, answer);
+    println!("{}", verdict);
+    if let Err(e) = ledger.record(day, part, answer, &verdict) {
+        eprintln!("warning: failed to record submission: {}", e);
+    }
+    if verdict == SubmitResult::Correct {
+        if let Err(e) = record_answer(year, day, part, answer) {
+            eprintln!("warning: failed to record answer: {}", e);
+        }
+    }
+    verdict.is_correct()
+}
+
+// Saves `answer` as the verified answer for the regression checks.
+pub fn record_answer(year: u32, day: u32, part: u32, answer: &str) -> io::Result<()> {
+    let mut answers = Answers::load(Answers::path_for(&cache_dir(), year))?;
+    if let Some(old) = answers.set(day, part, answer) {
+        println!(
+            "Replacing recorded answer {} for {} day {} part {}",
+            old, year, day, part
+        );
+    }
+    answers.save()
+}
+
+fn prompt_for_part(part: u32) -> bool {
     let mut input = String::new();
     print!("Run part {} (y/N)? ", part);
     io::stdout().flush().unwrap();
@@ -102,3 +322,73 @@ fn prompt_to_submit() -> bool {
 
     output == "y" || output == "Y" || output == "yes"
 }
+
+#[cfg(test)]
+mod runner_tests {
+    use super::*;
+    use crate::parsing::{parse_lines, parse_num};
+    use std::cell::Cell;
+
+    struct Counting {
+        parses: Cell<u32>,
+    }
+
+    impl ParsedSolution for Counting {
+        type Parsed = Vec<u32>;
+
+        fn year(&self) -> u32 {
+            2021
+        }
+        fn day(&self) -> u32 {
+            1
+        }
+        fn parse(&self, input: &str) -> Result<Vec<u32>, ParseError> {
+            self.parses.set(self.parses.get() + 1);
+            parse_lines(input, parse_num)
+        }
+        fn solve_part_one(&self, parsed: &Vec<u32>) -> Answer {
+            parsed.iter().sum::<u32>().into()
+        }
+        fn solve_part_two(&self, parsed: &Vec<u32>) -> Answer {
+            parsed.iter().product::<u32>().into()
+        }
+    }
+
+    #[test]
+    fn parses_once_when_prepared() {
+        let solution = Counting {
+            parses: Cell::new(0),
+        };
+        let prepared = solution.prepare("2\n3\n4").unwrap().unwrap();
+        assert_eq!(solve(&solution, Some(&*prepared), 1, ""), "9");
+        assert_eq!(solve(&solution, Some(&*prepared), 2, ""), "24");
+        assert_eq!(solution.parses.get(), 1);
+
+        // Called directly, each part parses for itself.
+        assert_eq!(solution.part_one("2\n3\n4"), "9");
+        assert_eq!(solution.parses.get(), 2);
+    }
+
+    #[test]
+    fn parse_errors_are_returned() {
+        let solution = Counting {
+            parses: Cell::new(0),
+        };
+        let err = solution.prepare("2\nthree\n4").err().unwrap();
+        assert_eq!(err.line, Some(2));
+    }
+
+    #[test]
+    fn never_submits_other_input() {
+        let solution = Counting {
+            parses: Cell::new(0),
+        };
+        let mut options = RunOptions::parse(&[]).unwrap();
+        options.input = Some(InputSource::File(PathBuf::from("sample.txt")));
+        options.submit = Some(true);
+        let err = run_with_options(&solution, &options).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        // Turned down before reading or solving anything.
+        assert_eq!(solution.parses.get(), 0);
+    }
+}
@@ -0,0 +1,374 @@
+use crate::coord::Coord;
+use crate::grid::Grid;
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// The cheapest way found from the start to a goal: its total cost, and every
+// state along the way (the start and the goal included).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+impl<S, C> Path<S, C> {
+    pub fn goal(&self) -> &S {
+        self.states.last().unwrap()
+    }
+}
+
+// A state waiting to be expanded.  Ordered so that the max-heap pops the
+// lowest estimate first, preferring the one furthest along on a tie.
+struct Queued<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> Ord for Queued<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then(self.cost.cmp(&other.cost))
+    }
+}
+
+impl<C: Ord> PartialOrd for Queued<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for Queued<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Queued<C> {}
+
+// The cheapest path from `start` to any state for which `is_goal` is true.
+// `successors` gives the states reachable in one move and what each move
+// costs; costs mustn't be negative.  States can be anything hashable, so
+// puzzles that care about more than position (like which way you're facing)
+// just put that in the state too.
+pub fn dijkstra<S, C, I, FS, FG>(start: S, successors: FS, is_goal: FG) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+    FS: FnMut(&S) -> I,
+    FG: FnMut(&S) -> bool,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+// As dijkstra, but `heuristic` guesses the remaining cost from each state to
+// speed things up.  For the answer to be right the guess must never be more
+// than the real cost (a manhattan distance on a grid where every step costs
+// at least 1, say).
+pub fn astar<S, C, I, FS, FH, FG>(
+    start: S,
+    mut successors: FS,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+    FS: FnMut(&S) -> I,
+    FH: FnMut(&S) -> C,
+    FG: FnMut(&S) -> bool,
+{
+    // Every state seen so far, with the best known cost to it and the state
+    // that cost came through.
+    let mut seen: Vec<(S, C, Option<usize>)> = Vec::new();
+    let mut indices: HashMap<S, usize> = HashMap::new();
+    let mut queue: BinaryHeap<Queued<C>> = BinaryHeap::new();
+
+    queue.push(Queued {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        index: 0,
+    });
+    indices.insert(start.clone(), 0);
+    seen.push((start, C::default(), None));
+
+    while let Some(Queued { cost, index, .. }) = queue.pop() {
+        if cost > seen[index].1 {
+            // A cheaper way here has been found since this was queued.
+            continue;
+        }
+        if is_goal(&seen[index].0) {
+            let mut states = Vec::new();
+            let mut at = Some(index);
+            while let Some(i) = at {
+                states.push(seen[i].0.clone());
+                at = seen[i].2;
+            }
+            states.reverse();
+            return Some(Path { cost, states });
+        }
+        for (next, step) in successors(&seen[index].0) {
+            let next_cost = cost + step;
+            let next_index = match indices.entry(next) {
+                Entry::Occupied(e) => {
+                    let i = *e.get();
+                    if seen[i].1 <= next_cost {
+                        continue;
+                    }
+                    seen[i].1 = next_cost;
+                    seen[i].2 = Some(index);
+                    i
+                }
+                Entry::Vacant(e) => {
+                    let i = seen.len();
+                    seen.push((e.key().clone(), next_cost, Some(index)));
+                    e.insert(i);
+                    i
+                }
+            };
+            queue.push(Queued {
+                estimate: next_cost + heuristic(&seen[next_index].0),
+                cost: next_cost,
+                index: next_index,
+            });
+        }
+    }
+    None
+}
+
+// How many moves it takes to reach every state reachable from `start`, when
+// each move (given by `successors`) counts the same.
+pub fn bfs<S, I, FS>(start: S, successors: FS) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    FS: FnMut(&S) -> I,
+{
+    multi_bfs([start], successors)
+}
+
+// As bfs, but from all of `starts` at once, so that each state gets its
+// distance from the nearest of them.
+pub fn multi_bfs<S, I, FS>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: FS,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    FS: FnMut(&S) -> I,
+{
+    let mut distances: HashMap<S, usize> = HashMap::new();
+    let mut queue: VecDeque<S> = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(e) = distances.entry(start) {
+            queue.push_back(e.key().clone());
+            e.insert(0);
+        }
+    }
+    while let Some(state) = queue.pop_front() {
+        let distance = distances[&state] + 1;
+        for next in successors(&state) {
+            if let Entry::Vacant(e) = distances.entry(next) {
+                queue.push_back(e.key().clone());
+                e.insert(distance);
+            }
+        }
+    }
+    distances
+}
+
+// The rest work on grids, moving between orthogonal neighbours.
+
+// Each cell's distance from the nearest of `starts`, moving only onto cells
+// that are `passable`, or None if it can't be reached.
+pub fn grid_distances<T, F>(
+    grid: &Grid<T>,
+    starts: impl IntoIterator<Item = Coord>,
+    mut passable: F,
+) -> Grid<Option<usize>>
+where
+    F: FnMut(&T) -> bool,
+{
+    let mut distances: Grid<Option<usize>> = grid.map(|_| None);
+    let mut queue: VecDeque<Coord> = VecDeque::new();
+    for start in starts {
+        if distances[start].is_none() {
+            distances[start] = Some(0);
+            queue.push_back(start);
+        }
+    }
+    while let Some(coord) = queue.pop_front() {
+        let distance = distances[coord].map(|d| d + 1);
+        for next in grid.neighbors4(coord) {
+            if distances[next].is_none() && passable(&grid[next]) {
+                distances[next] = distance;
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+// The cells reachable from `seed` through `passable` ones, nearest first.
+// The seed itself is always included.
+pub fn flood_fill<T, F>(grid: &Grid<T>, seed: Coord, mut passable: F) -> Vec<Coord>
+where
+    F: FnMut(&T) -> bool,
+{
+    let mut filled: Grid<bool> = grid.map(|_| false);
+    filled[seed] = true;
+    let mut order = vec![seed];
+    let mut i = 0;
+    while let Some(&coord) = order.get(i) {
+        for next in grid.neighbors4(coord) {
+            if !filled[next] && passable(&grid[next]) {
+                filled[next] = true;
+                order.push(next);
+            }
+        }
+        i += 1;
+    }
+    order
+}
+
+// Splits the grid into connected regions, where neighbouring cells are in the
+// same region if `joined` says so.  Returns every cell's region number
+// (numbered in the order their first cells come in the grid) and how many
+// regions there are.
+pub fn components<T, F>(grid: &Grid<T>, mut joined: F) -> (Grid<usize>, usize)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut labels: Grid<Option<usize>> = grid.map(|_| None);
+    let mut count = 0;
+    for start in grid.coords() {
+        if labels[start].is_some() {
+            continue;
+        }
+        labels[start] = Some(count);
+        let mut stack = vec![start];
+        while let Some(coord) = stack.pop() {
+            for next in grid.neighbors4(coord) {
+                if labels[next].is_none() && joined(&grid[coord], &grid[next]) {
+                    labels[next] = Some(count);
+                    stack.push(next);
+                }
+            }
+        }
+        count += 1;
+    }
+    (labels.map(|label| label.unwrap()), count)
+}
+
+#[cfg(test)]
+mod search_tests {
+    use super::*;
+
+    fn maze() -> Grid<char> {
+        Grid::parse("S..#....\n.#.#.##.\n.#...#..\n.####.#.\n......#E\n", |c| c)
+    }
+
+    fn moves(grid: &Grid<char>) -> impl FnMut(&Coord) -> Vec<(Coord, u32)> + '_ {
+        move |c| {
+            grid.neighbors4(*c)
+                .filter(|n| grid[*n] != '#')
+                .map(|n| (n, 1))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn shortest_paths() {
+        let grid = maze();
+        let end = grid.find(&'E').unwrap();
+        let path = dijkstra(Coord::ORIGIN, moves(&grid), |c| *c == end).unwrap();
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.states.len(), 16);
+        assert_eq!(path.states[0], Coord::ORIGIN);
+        assert_eq!(*path.goal(), end);
+        assert!(path.states.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+
+        let guided = astar(
+            Coord::ORIGIN,
+            moves(&grid),
+            |c| c.manhattan(end) as u32,
+            |c| *c == end,
+        )
+        .unwrap();
+        assert_eq!(guided.cost, 15);
+
+        // The start counts as a goal too.
+        let path = dijkstra(Coord::ORIGIN, moves(&grid), |_| true).unwrap();
+        assert_eq!((path.cost, path.states.len()), (0, 1));
+        assert_eq!(
+            dijkstra(Coord::ORIGIN, moves(&grid), |c| *c == Coord::new(0, 3)),
+            None
+        );
+    }
+
+    #[test]
+    fn weighted_states() {
+        // Going the long way round is cheaper than the expensive direct edge,
+        // and the state can be anything hashable.
+        let edges = |s: &&str| -> Vec<(&str, u64)> {
+            match *s {
+                "a" => vec![("b", 1), ("d", 10)],
+                "b" => vec![("c", 2)],
+                "c" => vec![("d", 3)],
+                _ => vec![],
+            }
+        };
+        let path = dijkstra("a", edges, |s| *s == "d").unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.states, vec!["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn breadth_first() {
+        let grid = maze();
+        let end = grid.find(&'E').unwrap();
+        let steps =
+            |c: &Coord| -> Vec<Coord> { grid.neighbors4(*c).filter(|n| grid[*n] != '#').collect() };
+        let distances = bfs(Coord::ORIGIN, steps);
+        assert_eq!(distances[&end], 15);
+        // Every open cell is reachable, and only those.
+        assert_eq!(distances.len(), 27);
+        assert_eq!(distances.get(&Coord::new(0, 3)), None);
+
+        // From both ends at once, nothing is as far away as the far end was.
+        let both = grid_distances(&grid, [Coord::ORIGIN, end], |c| *c != '#');
+        assert_eq!(both[end], Some(0));
+        assert_eq!(both[Coord::new(0, 3)], None);
+        assert_eq!(both.iter().filter_map(|(_, d)| *d).max(), Some(10));
+        let from_both = multi_bfs([Coord::ORIGIN, end], steps);
+        for (coord, distance) in both.iter() {
+            assert_eq!(from_both.get(&coord), distance.as_ref());
+        }
+    }
+
+    #[test]
+    fn filling() {
+        let grid = Grid::parse("..#..\n..#..\n###..\n.....\n", |c| c);
+        let filled = flood_fill(&grid, Coord::ORIGIN, |c| *c == '.');
+        assert_eq!(filled.len(), 4);
+        assert_eq!(filled[0], Coord::ORIGIN);
+        assert_eq!(flood_fill(&grid, Coord::new(0, 4), |c| *c == '.').len(), 11);
+        // The seed counts even if it isn't passable.
+        assert_eq!(flood_fill(&grid, Coord::new(0, 2), |c| *c == '#').len(), 5);
+
+        let (labels, count) = components(&grid, |a, b| a == b);
+        assert_eq!(count, 3);
+        assert_eq!(labels[Coord::ORIGIN], 0);
+        assert_eq!(labels[Coord::new(2, 0)], 1);
+        assert_eq!(labels[Coord::new(3, 0)], 2);
+        assert_eq!(labels[Coord::new(0, 3)], labels[Coord::new(3, 0)]);
+    }
+}
@@ -0,0 +1,134 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+pub const SESSION_FLAG: &str = "--session";
+
+#[derive(Debug)]
+pub enum SessionError {
+    // Nothing was found anywhere we looked.
+    Missing { config_file: Option<PathBuf> },
+    // The config file exists but couldn't be read.
+    Unreadable { path: PathBuf, error: io::Error },
+}
+
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SessionError::Missing { config_file } => {
+                write!(
+                    f,
+                    "no AoC session token found; pass {} <token>, set {}",
+                    SESSION_FLAG, SESSION_ENV_VAR
+                )?;
+                match config_file {
+                    Some(path) => write!(f, ", or write it to {}", path.display()),
+                    None => write!(f, " (no config directory could be determined)"),
+                }
+            }
+            SessionError::Unreadable { path, error } => {
+                write!(
+                    f,
+                    "couldn't read session token from {}: {}",
+                    path.display(),
+                    error
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SessionError {}
+
+// Looks for the session token in (highest priority first) the command line
+// arguments, the AOC_SESSION environment variable, and `aoc/session` inside
+// the user's config directory.
+pub fn find_session(args: &[String]) -> Result<String, SessionError> {
+    if let Some(token) = session_from_args(args) {
+        return Ok(token);
+    }
+    if let Ok(token) = env::var(SESSION_ENV_VAR) {
+        if !token.trim().is_empty() {
+            return Ok(token.trim().to_string());
+        }
+    }
+    let config_file = session_file();
+    if let Some(path) = &config_file {
+        match fs::read_to_string(path) {
+            Ok(contents) if !contents.trim().is_empty() => return Ok(contents.trim().to_string()),
+            Ok(_) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => {
+                return Err(SessionError::Unreadable {
+                    path: path.clone(),
+                    error,
+                })
+            }
+        }
+    }
+    Err(SessionError::Missing { config_file })
+}
+
+// Accepts both `--session <token>` and `--session=<token>`.
+pub fn session_from_args(args: &[String]) -> Option<String> {
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == SESSION_FLAG {
+            return iter.next().map(|t| t.trim().to_string());
+        }
+        if let Some(token) = arg.strip_prefix("--session=") {
+            return Some(token.trim().to_string());
+        }
+    }
+    None
+}
+
+pub fn config_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os("XDG_CONFIG_HOME").filter(|d| !d.is_empty()) {
+        return Some(PathBuf::from(dir));
+    }
+    if cfg!(windows) {
+        if let Some(dir) = env::var_os("APPDATA") {
+            return Some(PathBuf::from(dir));
+        }
+    }
+    env::var_os("HOME").map(|home| PathBuf::from(home).join(".config"))
+}
+
+pub fn session_file() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("aoc").join("session"))
+}
+
+#[cfg(test)]
+mod session_tests {
+    use super::*;
+
+    fn args(a: &[&str]) -> Vec<String> {
+        a.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn flag_forms() {
+        assert_eq!(
+            session_from_args(&args(&["day1", "--session", "abc"])),
+            Some("abc".to_string())
+        );
+        assert_eq!(
+            session_from_args(&args(&["day1", "--session=def"])),
+            Some("def".to_string())
+        );
+        assert_eq!(session_from_args(&args(&["day1", "--session"])), None);
+        assert_eq!(session_from_args(&args(&["day1"])), None);
+    }
+
+    #[test]
+    fn flag_wins() {
+        assert_eq!(
+            find_session(&args(&["day1", "--session", "abc"])).unwrap(),
+            "abc"
+        );
+    }
+}
@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+
+// A point in N dimensions.
+pub type Point<const N: usize> = [i64; N];
+
+// The smallest box containing a set of points.  Both corners are inclusive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bounds<const N: usize> {
+    pub min: Point<N>,
+    pub max: Point<N>,
+}
+
+impl<const N: usize> Bounds<N> {
+    pub fn around(point: Point<N>) -> Bounds<N> {
+        Bounds {
+            min: point,
+            max: point,
+        }
+    }
+
+    pub fn contains(&self, point: &Point<N>) -> bool {
+        (0..N).all(|i| self.min[i] <= point[i] && point[i] <= self.max[i])
+    }
+
+    // Grows the box (if needed) so that it contains `point`.
+    pub fn include(&mut self, point: &Point<N>) {
+        for (i, &v) in point.iter().enumerate() {
+            self.min[i] = self.min[i].min(v);
+            self.max[i] = self.max[i].max(v);
+        }
+    }
+
+    // The box grown by `by` in every direction.
+    pub fn expand(&self, by: i64) -> Bounds<N> {
+        Bounds {
+            min: self.min.map(|v| v - by),
+            max: self.max.map(|v| v + by),
+        }
+    }
+
+    // How many values each axis covers.
+    pub fn size(&self) -> Point<N> {
+        let mut size = [0; N];
+        for (i, s) in size.iter_mut().enumerate() {
+            *s = self.max[i] - self.min[i] + 1;
+        }
+        size
+    }
+
+    // Every point in the box, with the last axis changing fastest.
+    pub fn points(&self) -> impl Iterator<Item = Point<N>> {
+        let bounds = *self;
+        let total: i64 = bounds.size().iter().product();
+        (0..total).map(move |mut i| {
+            let mut point = bounds.min;
+            for axis in (0..N).rev() {
+                let size = bounds.max[axis] - bounds.min[axis] + 1;
+                point[axis] += i % size;
+                i /= size;
+            }
+            point
+        })
+    }
+}
+
+// The 3^N - 1 points touching `point`, diagonals included.
+pub fn neighbors<const N: usize>(point: Point<N>) -> impl Iterator<Item = Point<N>> {
+    Bounds::around(point)
+        .expand(1)
+        .points()
+        .filter(move |p| *p != point)
+}
+
+// The 2N points one step along a single axis from `point`.
+pub fn orthogonal_neighbors<const N: usize>(point: Point<N>) -> impl Iterator<Item = Point<N>> {
+    (0..N).flat_map(move |axis| {
+        [-1, 1].into_iter().map(move |d| {
+            let mut p = point;
+            p[axis] += d;
+            p
+        })
+    })
+}
+
+// Cells at arbitrary integer coordinates, for puzzles where the area of
+// interest has no fixed size (or grows as the puzzle runs).  The bounding box
+// of the occupied cells is kept up to date as cells are added and removed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T, const N: usize> {
+    cells: HashMap<Point<N>, T>,
+    bounds: Option<Bounds<N>>,
+}
+
+impl<T, const N: usize> Default for SparseGrid<T, N> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+impl<T, const N: usize> SparseGrid<T, N> {
+    pub fn new() -> SparseGrid<T, N> {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // None when the grid is empty.
+    pub fn bounds(&self) -> Option<Bounds<N>> {
+        self.bounds
+    }
+
+    pub fn get(&self, point: &Point<N>) -> Option<&T> {
+        self.cells.get(point)
+    }
+
+    pub fn get_mut(&mut self, point: &Point<N>) -> Option<&mut T> {
+        self.cells.get_mut(point)
+    }
+
+    pub fn contains(&self, point: &Point<N>) -> bool {
+        self.cells.contains_key(point)
+    }
+
+    // Returns the old value, if there was one.
+    pub fn insert(&mut self, point: Point<N>, value: T) -> Option<T> {
+        self.track(&point);
+        self.cells.insert(point, value)
+    }
+
+    // The value at `point`, filling it in with `f` first if it's empty.
+    pub fn get_or_insert_with<F: FnOnce() -> T>(&mut self, point: Point<N>, f: F) -> &mut T {
+        self.track(&point);
+        self.cells.entry(point).or_insert_with(f)
+    }
+
+    pub fn remove(&mut self, point: &Point<N>) -> Option<T> {
+        let removed = self.cells.remove(point)?;
+        // Only a point on the edge of the box can make it shrink.
+        let on_edge = self
+            .bounds
+            .is_some_and(|b| (0..N).any(|i| point[i] == b.min[i] || point[i] == b.max[i]));
+        if on_edge {
+            self.bounds = None;
+            for p in self.cells.keys() {
+                match &mut self.bounds {
+                    Some(b) => b.include(p),
+                    None => self.bounds = Some(Bounds::around(*p)),
+                }
+            }
+        }
+        Some(removed)
+    }
+
+    fn track(&mut self, point: &Point<N>) {
+        match &mut self.bounds {
+            Some(b) => b.include(point),
+            None => self.bounds = Some(Bounds::around(*point)),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Point<N>, &T)> {
+        self.cells.iter()
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = &Point<N>> {
+        self.cells.keys()
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+
+    // Draws the plane through `at` spanned by `row_axis` (downwards) and
+    // `col_axis` (rightwards), over the bounding box.  `f` picks the character
+    // for each cell, empty or not.
+    pub fn render_slice<F>(&self, row_axis: usize, col_axis: usize, at: Point<N>, f: F) -> String
+    where
+        F: Fn(Option<&T>) -> char,
+    {
+        let Some(bounds) = self.bounds else {
+            return String::new();
+        };
+        let mut lines: Vec<String> = Vec::new();
+        for row in bounds.min[row_axis]..=bounds.max[row_axis] {
+            let mut point = at;
+            point[row_axis] = row;
+            lines.push(
+                (bounds.min[col_axis]..=bounds.max[col_axis])
+                    .map(|col| {
+                        point[col_axis] = col;
+                        f(self.get(&point))
+                    })
+                    .collect(),
+            );
+        }
+        lines.join("\n")
+    }
+}
+
+impl<T> SparseGrid<T, 2> {
+    // Draws the whole grid, taking points as [row, col].
+    pub fn render<F: Fn(Option<&T>) -> char>(&self, f: F) -> String {
+        self.render_slice(0, 1, [0, 0], f)
+    }
+}
+
+impl<T, const N: usize> FromIterator<(Point<N>, T)> for SparseGrid<T, N> {
+    fn from_iter<I: IntoIterator<Item = (Point<N>, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (point, value) in iter {
+            grid.insert(point, value);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod sparse_tests {
+    use super::*;
+
+    #[test]
+    fn tracks_bounds() {
+        let mut grid: SparseGrid<char, 3> = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert([0, 0, 0], 'a');
+        grid.insert([2, -1, 5], 'b');
+        grid.insert([1, 3, 0], 'c');
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: [0, -1, 0],
+                max: [2, 3, 5]
+            })
+        );
+        grid.remove(&[2, -1, 5]);
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: [0, 0, 0],
+                max: [1, 3, 0]
+            })
+        );
+        grid.remove(&[0, 0, 0]);
+        grid.remove(&[1, 3, 0]);
+        assert_eq!(grid.bounds(), None);
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    fn box_points() {
+        let bounds = Bounds {
+            min: [1, -1],
+            max: [2, 1],
+        };
+        assert_eq!(bounds.size(), [2, 3]);
+        let points: Vec<Point<2>> = bounds.points().collect();
+        assert_eq!(
+            points,
+            vec![[1, -1], [1, 0], [1, 1], [2, -1], [2, 0], [2, 1]]
+        );
+        assert!(bounds.contains(&[2, 0]));
+        assert!(!bounds.contains(&[0, 0]));
+    }
+
+    #[test]
+    fn neighbor_counts() {
+        assert_eq!(neighbors([0, 0]).count(), 8);
+        assert_eq!(neighbors([5, 5, 5]).count(), 26);
+        assert_eq!(neighbors([0, 0, 0, 0]).count(), 80);
+        assert!(neighbors([0, 0, 0]).all(|p| p != [0, 0, 0]));
+        let mut orthogonal: Vec<Point<2>> = orthogonal_neighbors([0, 0]).collect();
+        orthogonal.sort();
+        assert_eq!(orthogonal, vec![[-1, 0], [0, -1], [0, 1], [1, 0]]);
+    }
+
+    #[test]
+    fn rendering() {
+        let grid: SparseGrid<char, 2> = [([-1, 0], '#'), ([1, 2], '#')].into_iter().collect();
+        assert_eq!(grid.render(|c| *c.unwrap_or(&'.')), "#..\n...\n..#");
+
+        let cube: SparseGrid<(), 3> = [([0, 0, 1], ()), ([0, 1, 0], ()), ([1, 1, 1], ())]
+            .into_iter()
+            .collect();
+        let draw = |c: Option<&()>| if c.is_some() { '#' } else { '.' };
+        assert_eq!(cube.render_slice(0, 1, [0, 0, 0], draw), ".#\n..");
+        assert_eq!(cube.render_slice(0, 1, [0, 0, 1], draw), "#.\n.#");
+    }
+}
@@ -0,0 +1,151 @@
+use std::fmt;
+use std::time::Duration;
+
+// What adventofcode.com had to say about a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmitResult {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited { wait: Option<Duration> },
+    AlreadySolved,
+    // Anything we don't recognize, with the page's text so it can be shown.
+    Unknown(String),
+}
+
+impl SubmitResult {
+    pub fn from_html(html: &str) -> SubmitResult {
+        let text = main_text(html);
+        if text.contains("That's the right answer") {
+            SubmitResult::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("too high") {
+                SubmitResult::TooHigh
+            } else if text.contains("too low") {
+                SubmitResult::TooLow
+            } else {
+                SubmitResult::Wrong
+            }
+        } else if text.contains("You gave an answer too recently") {
+            SubmitResult::RateLimited {
+                wait: parse_wait(&text),
+            }
+        } else if text.contains("Did you already complete it") {
+            SubmitResult::AlreadySolved
+        } else {
+            SubmitResult::Unknown(text)
+        }
+    }
+
+    pub fn is_correct(&self) -> bool {
+        matches!(self, SubmitResult::Correct | SubmitResult::AlreadySolved)
+    }
+}
+
+impl fmt::Display for SubmitResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubmitResult::Correct => write!(f, "Correct!"),
+            SubmitResult::TooHigh => write!(f, "Wrong answer: too high."),
+            SubmitResult::TooLow => write!(f, "Wrong answer: too low."),
+            SubmitResult::Wrong => write!(f, "Wrong answer."),
+            SubmitResult::RateLimited { wait: Some(wait) } => write!(
+                f,
+                "Answered too recently; try again in {}s.",
+                wait.as_secs()
+            ),
+            SubmitResult::RateLimited { wait: None } => {
+                write!(f, "Answered too recently; try again later.")
+            }
+            SubmitResult::AlreadySolved => write!(f, "This part has already been solved."),
+            SubmitResult::Unknown(text) => write!(f, "Unrecognized response: {}", text),
+        }
+    }
+}
+
+// The interesting part of the page lives inside <main>; fall back to the whole
+// document if the layout ever changes, then strip the tags out.
+fn main_text(html: &str) -> String {
+    let main = html
+        .split_once("<main>")
+        .map(|(_, rest)| rest.split("</main>").next().unwrap_or(rest))
+        .unwrap_or(html);
+    let mut text = String::with_capacity(main.len());
+    let mut in_tag = false;
+    for c in main.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+// Pulls the delay out of "You have 1m 23s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    let mut secs = 0u64;
+    for part in text[start..end].split_whitespace() {
+        let (num, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let num: u64 = num.parse().ok()?;
+        secs += match unit {
+            "h" => num * 3600,
+            "m" => num * 60,
+            "s" => num,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+#[cfg(test)]
+mod submit_tests {
+    use super::*;
+
+    #[test]
+    fn fixtures() {
+        assert_eq!(
+            SubmitResult::from_html(include_str!("../../fixtures/submit/correct.html")),
+            SubmitResult::Correct
+        );
+        assert_eq!(
+            SubmitResult::from_html(include_str!("../../fixtures/submit/too_high.html")),
+            SubmitResult::TooHigh
+        );
+        assert_eq!(
+            SubmitResult::from_html(include_str!("../../fixtures/submit/too_low.html")),
+            SubmitResult::TooLow
+        );
+        assert_eq!(
+            SubmitResult::from_html(include_str!("../../fixtures/submit/wrong.html")),
+            SubmitResult::Wrong
+        );
+        assert_eq!(
+            SubmitResult::from_html(include_str!("../../fixtures/submit/rate_limited.html")),
+            SubmitResult::RateLimited {
+                wait: Some(Duration::from_secs(83))
+            }
+        );
+        assert_eq!(
+            SubmitResult::from_html(include_str!("../../fixtures/submit/already_solved.html")),
+            SubmitResult::AlreadySolved
+        );
+    }
+
+    #[test]
+    fn unknown_layout() {
+        assert_eq!(
+            SubmitResult::from_html("<p>Something  else\nentirely</p>"),
+            SubmitResult::Unknown("Something else entirely".to_string())
+        );
+        assert_eq!(
+            parse_wait("You have 12s left to wait."),
+            Some(Duration::from_secs(12))
+        );
+        assert_eq!(parse_wait("You have a while left to wait."), None);
+    }
+}
@@ -0,0 +1,165 @@
+use aoc_helpers::answers::Answers;
+use aoc_helpers::options::{RunOptions, USAGE};
+use aoc_helpers::registry::Registry;
+use aoc_helpers::regression::{all_passed, bad_answers, check, format_table, no_input};
+use aoc_helpers::runner::{cache_dir, record_answer, run_with_options, try_get_input, AocSolution};
+use std::env;
+use std::process;
+
+const COMMANDS: &str = "Usage:
+    aoc <year> <day> [options]    run a single day
+    aoc <year> all [options]      run every implemented day of a year
+    aoc all [options]             run every implemented day of every year
+    aoc list [year]               show which days are implemented
+    aoc check [year [day]]        compare answers against the recorded ones
+    aoc record <year> <day>       record the current answers as correct";
+
+fn registries() -> Vec<Registry> {
+    vec![
+        aoc_2015_libs::registry(),
+        aoc_2020_libs::registry(),
+        aoc_2021_libs::registry(),
+        aoc_2023_libs::registry(),
+    ]
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("error: {}\n\n{}\n\n{}", message, COMMANDS, USAGE);
+    process::exit(2);
+}
+
+fn find_year(registries: &[Registry], year: &str) -> usize {
+    let year: u32 = year
+        .parse()
+        .unwrap_or_else(|_| usage_error(&format!("invalid year '{}'", year)));
+    registries
+        .iter()
+        .position(|r| r.year() == year)
+        .unwrap_or_else(|| usage_error(&format!("no solutions for {}", year)))
+}
+
+// Resolves `all`, `<year>`, `<year> all` or `<year> <day>` to solutions.
+fn select<'a>(registries: &'a [Registry], targets: &[&str]) -> Vec<&'a dyn AocSolution> {
+    match targets {
+        [] | ["all"] => registries.iter().flat_map(|r| r.solutions()).collect(),
+        [year] | [year, "all"] => registries[find_year(registries, year)]
+            .solutions()
+            .collect(),
+        [year, day] => {
+            let registry = &registries[find_year(registries, year)];
+            let day: u32 = day
+                .parse()
+                .unwrap_or_else(|_| usage_error(&format!("invalid day '{}'", day)));
+            match registry.get(day) {
+                Some(solution) => vec![solution],
+                None => {
+                    eprintln!("{} day {} isn't implemented yet", registry.year(), day);
+                    process::exit(1);
+                }
+            }
+        }
+        _ => usage_error("expected a year and day, 'all', or 'list'"),
+    }
+}
+
+fn run_checks(selected: &[&dyn AocSolution], refresh: bool) -> bool {
+    let mut outcomes = Vec::new();
+    for solution in selected {
+        let (year, day) = (solution.year(), solution.day());
+        // A broken answers file only spoils that year's rows, not the whole check.
+        let answers = match Answers::load(Answers::path_for(&cache_dir(), year)) {
+            Ok(answers) => answers,
+            Err(e) => {
+                let reason = format!("failed to load answers: {}", e);
+                outcomes.extend(bad_answers(year, day, &reason));
+                continue;
+            }
+        };
+        match try_get_input(year, day, refresh) {
+            Ok(input) => outcomes.extend(check(*solution, &input, &answers)),
+            Err(e) => outcomes.extend(no_input(year, day, &e.to_string())),
+        }
+    }
+    print!("{}", format_table(&outcomes));
+    all_passed(&outcomes)
+}
+
+fn record(solution: &dyn AocSolution, refresh: bool) {
+    let (year, day) = (solution.year(), solution.day());
+    let input = try_get_input(year, day, refresh).unwrap_or_else(|e| {
+        eprintln!("error: {} day {}: {}", year, day, e);
+        process::exit(1);
+    });
+    for (part, answer) in [
+        (1, solution.part_one(&input)),
+        (2, solution.part_two(&input)),
+    ] {
+        if !answer.is_implemented() || answer.to_string().is_empty() {
+            continue;
+        }
+        println!("{} day {} part {}: {}", year, day, part, answer);
+        if let Err(e) = record_answer(year, day, part, &answer.to_string()) {
+            eprintln!("error: failed to record answer: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let split = args
+        .iter()
+        .position(|a| a.starts_with('-'))
+        .unwrap_or(args.len());
+    let (targets, flags) = args.split_at(split);
+    let mut options = RunOptions::parse(flags).unwrap_or_else(|e| usage_error(&e));
+    if options.help {
+        println!("{}\n\n{}", COMMANDS, USAGE);
+        return;
+    }
+
+    let registries = registries();
+    let targets: Vec<&str> = targets.iter().map(|s| s.as_str()).collect();
+    let selected = match targets.as_slice() {
+        ["list"] => {
+            for registry in &registries {
+                println!("{}", registry.summary());
+            }
+            return;
+        }
+        ["check", rest @ ..] => {
+            if !run_checks(&select(&registries, rest), options.refresh) {
+                process::exit(1);
+            }
+            return;
+        }
+        ["record", year, day] => {
+            for solution in select(&registries, &[year, day]) {
+                record(solution, options.refresh);
+            }
+            return;
+        }
+        ["list", year] | [year] if *year != "all" => {
+            println!("{}", registries[find_year(&registries, year)].summary());
+            return;
+        }
+        [] => usage_error("expected a year and day, 'all', or 'list'"),
+        rest => select(&registries, rest),
+    };
+
+    // Prompting for every single day would be tedious, so running more than one
+    // day at a time always uses the options as given.
+    if selected.len() > 1 {
+        options.saw_flag = true;
+    }
+    let mut failed = false;
+    for solution in selected {
+        if let Err(e) = run_with_options(solution, &options) {
+            eprintln!("error: {} day {}: {}", solution.year(), solution.day(), e);
+            failed = true;
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
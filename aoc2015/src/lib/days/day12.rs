@@ -1,12 +1,7 @@
 use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -16,27 +11,17 @@ impl AocSolution for Solution {
         12
     }
 
-    fn part_one(&self, input: &str) -> String {
-        part_one(input).to_string()
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
     }
 
-    fn part_two(&self, input: &str) -> String {
-        part_two(input).to_string()
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
     }
 }
 
 pub fn sum_json_nums(input: &str) -> i64 {
-    let mut sum = 0_i64;
-    let mut number = String::new();
-    for c in input.chars() {
-        if c == '-' || c.is_numeric() {
-            number.push(c);
-        } else if !number.is_empty() {
-            sum += number.parse::<i64>().unwrap();
-            number = String::new();
-        }
-    }
-    sum
+    ints::<i64>(input).map(|n| n.unwrap()).sum()
 }
 
 pub fn remove_red(input: &str) -> String {
@@ -0,0 +1,191 @@
+use aoc_helpers::graph::Graph;
+use aoc_helpers::parsing::*;
+use aoc_helpers::runner::*;
+use aoc_helpers::record::Format;
+use lazy_static::lazy_static;
+
+pub struct Solution {}
+
+impl AocSolution for Solution {
+    fn year(&self) -> u32 {
+        2015
+    }
+    fn day(&self) -> u32 {
+        13
+    }
+
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(&self, _input: &str) -> Answer {
+        Answer::Unimplemented
+    }
+}
+
+lazy_static! {
+    static ref FORMAT: Format = Format::new(
+        "{name} would {sign:gain|lose} {n} happiness units by sitting next to {other}."
+    );
+}
+
+fn parse_line(line: &str) -> (&str, &str, i64) {
+    let record = FORMAT.parse(line).unwrap();
+    let mut units: i64 = record.get("n").unwrap();
+    if record.str("sign") == "lose" {
+        units *= -1;
+    }
+    (record.str("name"), record.str("other"), units)
+}
+
+pub fn build_graph(input: &str) -> Graph<i64> {
+    let mut graph: Graph<i64> = Graph::new();
+    for line in input.lines() {
+        let (name, other, units) = parse_line(line);
+        graph.add_edge(name, other, units);
+    }
+    graph
+}
+
+// How much happier the two of them get, together, from sitting side by side.
+fn happiness(graph: &Graph<i64>, a: usize, b: usize) -> i64 {
+    graph.weight(a, b).copied().unwrap_or(0) + graph.weight(b, a).copied().unwrap_or(0)
+}
+
+// The best that can be done by seating everyone in `unseated` after `last`,
+// and then closing the circle back round to `first`.
+fn best_seating(graph: &Graph<i64>, first: usize, last: usize, unseated: Vec<usize>) -> i64 {
+    if unseated.is_empty() {
+        return happiness(graph, last, first);
+    }
+
+    let mut best = i64::MIN;
+    for (i, person) in unseated.iter().enumerate() {
+        let mut rest = unseated.clone();
+        rest.remove(i);
+        let total = happiness(graph, last, *person) + best_seating(graph, first, *person, rest);
+        if total > best {
+            best = total;
+        }
+    }
+    best
+}
+
+pub fn part_one(input: &str) -> i64 {
+    let graph = build_graph(input);
+    if graph.is_empty() {
+        return 0;
+    }
+
+    // The table is round, so it doesn't matter where the first person sits;
+    // try every order for everyone else.
+    best_seating(&graph, 0, 0, graph.nodes().skip(1).collect())
+}
+
+#[cfg(test)]
+mod day12_tests {
+    use super::*;
+
+    #[test]
+    fn samples_part1() {
+        assert_eq!(
+            part_one(
+                "Alice would gain 54 happiness units by sitting next to Bob.
+Alice would lose 79 happiness units by sitting next to Carol.
+Alice would lose 2 happiness units by sitting next to David.
+Bob would gain 83 happiness units by sitting next to Alice.
+Bob would lose 7 happiness units by sitting next to Carol.
+Bob would lose 63 happiness units by sitting next to David.
+Carol would lose 62 happiness units by sitting next to Alice.
+Carol would gain 60 happiness units by sitting next to Bob.
+Carol would gain 55 happiness units by sitting next to David.
+David would gain 46 happiness units by sitting next to Alice.
+David would lose 7 happiness units by sitting next to Bob.
+David would gain 41 happiness units by sitting next to Carol."
+            ),
+            330
+        );
+    }
+
+    #[test]
+    fn greedy_seating_is_not_enough() {
+        // Seating the happiest pair first and growing the table from there
+        // used to give -6 here, which no seating actually reaches.  The best is
+        // Alice, Carol, Bob, David: -12 - 15 + 1 + 8.
+        assert_eq!(
+            part_one(
+                "Alice would lose 19 happiness units by sitting next to Bob.
+Alice would lose 19 happiness units by sitting next to Carol.
+Alice would gain 14 happiness units by sitting next to David.
+Bob would lose 20 happiness units by sitting next to Alice.
+Bob would gain 4 happiness units by sitting next to Carol.
+Bob would lose 7 happiness units by sitting next to David.
+Carol would gain 7 happiness units by sitting next to Alice.
+Carol would lose 19 happiness units by sitting next to Bob.
+Carol would gain 13 happiness units by sitting next to David.
+David would lose 6 happiness units by sitting next to Alice.
+David would gain 8 happiness units by sitting next to Bob.
+David would gain 11 happiness units by sitting next to Carol."
+            ),
+            -18
+        );
+    }
+
+    #[test]
+    fn samples_part2() {
+        let solution = Solution {};
+        assert_eq!(solution.part_two(""), Answer::Unimplemented);
+    }
+
+    #[test]
+    fn parse_line_test() {
+        assert_eq!(
+            parse_line("Alice would gain 54 happiness units by sitting next to Bob."),
+            ("Alice", "Bob", 54)
+        );
+        assert_eq!(
+            parse_line("Alice would lose 79 happiness units by sitting next to Carol."),
+            ("Alice", "Carol", -79)
+        );
+        assert_eq!(
+            parse_line("Alice would lose 2 happiness units by sitting next to David."),
+            ("Alice", "David", -2)
+        );
+        assert_eq!(
+            parse_line("Bob would gain 83 happiness units by sitting next to Alice."),
+            ("Bob", "Alice", 83)
+        );
+        assert_eq!(
+            parse_line("Bob would lose 7 happiness units by sitting next to Carol."),
+            ("Bob", "Carol", -7)
+        );
+        assert_eq!(
+            parse_line("Bob would lose 63 happiness units by sitting next to David."),
+            ("Bob", "David", -63)
+        );
+        assert_eq!(
+            parse_line("Carol would lose 62 happiness units by sitting next to Alice."),
+            ("Carol", "Alice", -62)
+        );
+        assert_eq!(
+            parse_line("Carol would gain 60 happiness units by sitting next to Bob."),
+            ("Carol", "Bob", 60)
+        );
+        assert_eq!(
+            parse_line("Carol would gain 55 happiness units by sitting next to David."),
+            ("Carol", "David", 55)
+        );
+        assert_eq!(
+            parse_line("David would gain 46 happiness units by sitting next to Alice."),
+            ("David", "Alice", 46)
+        );
+        assert_eq!(
+            parse_line("David would lose 7 happiness units by sitting next to Bob."),
+            ("David", "Bob", -7)
+        );
+        assert_eq!(
+            parse_line("David would gain 41 happiness units by sitting next to Carol."),
+            ("David", "Carol", 41)
+        );
+    }
+}
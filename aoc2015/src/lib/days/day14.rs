@@ -1,12 +1,7 @@
 use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -16,12 +11,12 @@ impl AocSolution for Solution {
         14
     }
 
-    fn part_one(&self, _input: &str) -> String {
-        "".to_string()
+    fn part_one(&self, _input: &str) -> Answer {
+        Answer::Unimplemented
     }
 
-    fn part_two(&self, _input: &str) -> String {
-        "".to_string()
+    fn part_two(&self, _input: &str) -> Answer {
+        Answer::Unimplemented
     }
 }
 
@@ -32,12 +27,12 @@ mod day1_tests {
     #[test]
     fn samples_part1() {
         let solution = Solution {};
-        assert_eq!(solution.part_one(""), "");
+        assert_eq!(solution.part_one(""), Answer::Unimplemented);
     }
 
     #[test]
     fn samples_part2() {
         let solution = Solution {};
-        assert_eq!(solution.part_two(""), "");
+        assert_eq!(solution.part_two(""), Answer::Unimplemented);
     }
 }
@@ -2,12 +2,7 @@ use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
 use std::cmp::{max, min};
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -17,12 +12,12 @@ impl AocSolution for Solution {
         2
     }
 
-    fn part_one(&self, input: &str) -> String {
-        part_one(input).to_string()
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
     }
 
-    fn part_two(&self, input: &str) -> String {
-        part_two(input).to_string()
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
     }
 }
 
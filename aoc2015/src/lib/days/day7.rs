@@ -1,14 +1,9 @@
-use aoc_2015_libs::circuit::Circuit;
+use crate::circuit::Circuit;
 use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
 use regex::{Captures, Regex};
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -18,12 +13,12 @@ impl AocSolution for Solution {
         7
     }
 
-    fn part_one(&self, input: &str) -> String {
-        part_one(input).to_string()
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
     }
 
-    fn part_two(&self, input: &str) -> String {
-        part_two(input).to_string()
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
     }
 }
 
@@ -1,14 +1,9 @@
+use aoc_helpers::graph::Graph;
 use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
-use std::collections::HashMap;
 use std::iter::FromIterator;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -18,19 +13,17 @@ impl AocSolution for Solution {
         9
     }
 
-    fn part_one(&self, input: &str) -> String {
-        part_one(input).to_string()
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
     }
 
-    fn part_two(&self, input: &str) -> String {
-        part_two(input).to_string()
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
     }
 }
 
-type Edges = HashMap<String, HashMap<String, usize>>;
-
-pub fn build_graph(input: &str) -> Edges {
-    let mut edges: Edges = HashMap::new();
+pub fn build_graph(input: &str) -> Graph<usize> {
+    let mut graph: Graph<usize> = Graph::new();
     for line in input.lines() {
         let pieces: Vec<&str> = line.split(' ').collect();
         if pieces.len() != 5 {
@@ -39,29 +32,30 @@ pub fn build_graph(input: &str) -> Edges {
         let start = pieces[0];
         let end = pieces[2];
         let dist = pieces[4].parse::<usize>().unwrap();
-        let city_edges = edges.entry(start.to_string()).or_insert(HashMap::new());
-        city_edges.insert(end.to_string(), dist);
-        let city_edges = edges.entry(end.to_string()).or_insert(HashMap::new());
-        city_edges.insert(start.to_string(), dist);
+        graph.add_undirected(start, end, dist);
     }
-    edges
+    graph
 }
 
-pub fn find_shortest(start: &str, cities: Vec<String>, edges: &Edges) -> usize {
+fn distance(graph: &Graph<usize>, from: usize, to: usize) -> usize {
+    *graph.weight(from, to).unwrap()
+}
+
+pub fn find_shortest(start: Option<usize>, cities: Vec<usize>, graph: &Graph<usize>) -> usize {
     if cities.len() == 0 {
         return 0;
     }
     if cities.len() == 1 {
-        return *edges.get(start).unwrap().get(&cities[0]).unwrap();
+        return start.map_or(0, |start| distance(graph, start, cities[0]));
     }
 
     let mut shortest = usize::MAX;
     for (i, city) in cities.iter().enumerate() {
         let mut new_cities = cities.clone();
         new_cities.remove(i);
-        let mut dist = find_shortest(&city, new_cities, edges);
-        if start != "" {
-            dist += *edges.get(start).unwrap().get(city).unwrap();
+        let mut dist = find_shortest(Some(*city), new_cities, graph);
+        if let Some(start) = start {
+            dist += distance(graph, start, *city);
         }
         if dist < shortest {
             shortest = dist;
@@ -70,21 +64,21 @@ pub fn find_shortest(start: &str, cities: Vec<String>, edges: &Edges) -> usize {
     shortest
 }
 
-pub fn find_longest(start: &str, cities: Vec<String>, edges: &Edges) -> usize {
+pub fn find_longest(start: Option<usize>, cities: Vec<usize>, graph: &Graph<usize>) -> usize {
     if cities.len() == 0 {
         return 0;
     }
     if cities.len() == 1 {
-        return *edges.get(start).unwrap().get(&cities[0]).unwrap();
+        return start.map_or(0, |start| distance(graph, start, cities[0]));
     }
 
     let mut longest = 0;
     for (i, city) in cities.iter().enumerate() {
         let mut new_cities = cities.clone();
         new_cities.remove(i);
-        let mut dist = find_longest(&city, new_cities, edges);
-        if start != "" {
-            dist += *edges.get(start).unwrap().get(city).unwrap();
+        let mut dist = find_longest(Some(*city), new_cities, graph);
+        if let Some(start) = start {
+            dist += distance(graph, start, *city);
         }
         if dist > longest {
             longest = dist;
@@ -95,33 +89,39 @@ pub fn find_longest(start: &str, cities: Vec<String>, edges: &Edges) -> usize {
 
 pub fn part_one(input: &str) -> i64 {
     // Build the graph.
-    let edges: Edges = build_graph(input);
+    let graph = build_graph(input);
 
     // Brute force traveling salesman solution.
-    let cities = Vec::from_iter(edges.keys().map(|k| k.to_string()));
-    find_shortest("", cities, &edges) as i64
+    let cities = Vec::from_iter(graph.nodes());
+    find_shortest(None, cities, &graph) as i64
 }
 
 pub fn part_two(input: &str) -> i64 {
     // Build the graph.
-    let edges: Edges = build_graph(input);
+    let graph = build_graph(input);
 
     // Brute force traveling salesman solution.
-    let cities = Vec::from_iter(edges.keys().map(|k| k.to_string()));
-    find_longest("", cities, &edges) as i64
+    let cities = Vec::from_iter(graph.nodes());
+    find_longest(None, cities, &graph) as i64
 }
 
 #[cfg(test)]
 mod day9_tests {
     use super::*;
 
+    const SAMPLE: &str = "London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141";
+
     #[test]
     fn samples_part1() {
         assert_eq!(part_one(""), 0);
+        assert_eq!(part_one(SAMPLE), 605);
     }
 
     #[test]
     fn samples_part2() {
         assert_eq!(part_two(""), 0);
+        assert_eq!(part_two(SAMPLE), 982);
     }
 }
@@ -1 +1,23 @@
 pub mod circuit;
+pub mod days;
+
+use aoc_helpers::registry::Registry;
+use days::*;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new(2015);
+    registry.add(day1::Solution {});
+    registry.add(day2::Solution {});
+    registry.add(day3::Solution {});
+    registry.add(day4::Solution {});
+    registry.add(day5::Solution {});
+    registry.add(day6::Solution {});
+    registry.add(day7::Solution {});
+    registry.add(day8::Solution {});
+    registry.add(day9::Solution {});
+    registry.add(day10::Solution {});
+    registry.add(day11::Solution {});
+    registry.add(day12::Solution {});
+    registry.add(day13::Solution {});
+    registry
+}
@@ -0,0 +1,111 @@
+use aoc_helpers::automaton::{Automaton, Neighborhood};
+use aoc_helpers::grid::Grid;
+use aoc_helpers::parsing::*;
+use aoc_helpers::runner::*;
+
+pub struct Solution {}
+
+impl AocSolution for Solution {
+    fn year(&self) -> u32 {
+        2020
+    }
+    fn day(&self) -> u32 {
+        11
+    }
+
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[derive(Clone, Copy, Eq, Debug, PartialEq)]
+enum State {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+fn parse_seats(input: &str) -> Grid<State> {
+    Grid::parse(input, |c| match c {
+        '.' => State::Floor,
+        'L' => State::Empty,
+        '#' => State::Occupied,
+        _ => panic!("unexpected seat: {}", c),
+    })
+}
+
+// Runs the seating rules until nobody moves.  People leave a seat when at least
+// `crowd` of the seats they consider are taken.
+fn settle(seats: Grid<State>, neighborhood: Neighborhood<State>, crowd: usize) -> usize {
+    let rule = |seat: &State, neighbors: &[&State]| {
+        let occupied = neighbors.iter().filter(|s| ***s == State::Occupied).count();
+        match seat {
+            State::Empty if occupied == 0 => State::Occupied,
+            State::Occupied if occupied >= crowd => State::Empty,
+            _ => *seat,
+        }
+    };
+    let mut automaton = Automaton::new(seats, neighborhood, rule);
+    automaton.run_until_stable();
+    automaton.grid().find_all(&State::Occupied).count()
+}
+
+pub fn part_one(input: &str) -> usize {
+    settle(parse_seats(input), Neighborhood::Moore, 4)
+}
+
+// Floor doesn't block the view, so people look past it to the first seat.
+pub fn part_two(input: &str) -> usize {
+    settle(
+        parse_seats(input),
+        Neighborhood::LineOfSight(|s| *s == State::Floor),
+        5,
+    )
+}
+
+#[cfg(test)]
+mod day11_tests {
+    use super::*;
+
+    #[test]
+    fn samples_part1() {
+        assert_eq!(
+            part_one(
+                "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL"
+            ),
+            37
+        );
+    }
+
+    #[test]
+    fn samples_part2() {
+        assert_eq!(
+            part_two(
+                "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL"
+            ),
+            26
+        );
+    }
+}
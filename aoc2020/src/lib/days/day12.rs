@@ -0,0 +1,121 @@
+use aoc_helpers::coord::{Coord, Direction};
+use aoc_helpers::runner::*;
+
+pub struct Solution {}
+
+impl AocSolution for Solution {
+    fn year(&self) -> u32 {
+        2020
+    }
+    fn day(&self) -> u32 {
+        12
+    }
+
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Move(Direction, i64),
+    // Quarter turns; positive is clockwise.
+    Turn(i32),
+    Forward(i64),
+}
+
+pub fn parse_actions(input: &str) -> Vec<Action> {
+    let mut actions: Vec<Action> = Vec::new();
+    for line in input.lines() {
+        let c: char = line.as_bytes()[0] as char;
+        let val: i64 = line[1..].parse::<i64>().unwrap();
+        let action = match c {
+            'L' => Action::Turn(-(val / 90) as i32),
+            'R' => Action::Turn((val / 90) as i32),
+            'F' => Action::Forward(val),
+            _ => match Direction::from_char(c) {
+                Some(dir) => Action::Move(dir, val),
+                None => panic!("invalid action: {}", line),
+            },
+        };
+        actions.push(action);
+    }
+    actions
+}
+
+pub fn follow_directions(actions: &Vec<Action>) -> Coord {
+    let mut facing = Direction::East;
+    let mut ship = Coord::ORIGIN;
+    for action in actions {
+        match action {
+            Action::Move(dir, val) => ship += dir.delta() * *val,
+            Action::Turn(quarters) => facing = facing.rotate(*quarters),
+            Action::Forward(val) => ship += facing.delta() * *val,
+        };
+    }
+    ship
+}
+
+pub fn move_waypoint(actions: &Vec<Action>) -> Coord {
+    let mut waypoint = Direction::East.delta() * 10 + Direction::North.delta();
+    let mut ship = Coord::ORIGIN;
+    for action in actions {
+        match action {
+            Action::Move(dir, val) => waypoint += dir.delta() * *val,
+            Action::Turn(quarters) => {
+                for _ in 0..quarters.rem_euclid(4) {
+                    waypoint = waypoint.rotate_right();
+                }
+            }
+            Action::Forward(val) => ship += waypoint * *val,
+        };
+    }
+    ship
+}
+
+pub fn part_one(input: &str) -> i64 {
+    let actions = parse_actions(input);
+    follow_directions(&actions).manhattan(Coord::ORIGIN)
+}
+
+pub fn part_two(input: &str) -> i64 {
+    let actions = parse_actions(input);
+    move_waypoint(&actions).manhattan(Coord::ORIGIN)
+}
+
+#[cfg(test)]
+mod day12_tests {
+    use super::*;
+
+    #[test]
+    fn samples_part1() {
+        assert_eq!(
+            part_one(
+                "F10
+N3
+F7
+R90
+F11"
+            ),
+            25
+        );
+    }
+
+    #[test]
+    fn samples_part2() {
+        assert_eq!(
+            part_two(
+                "F10
+N3
+F7
+R90
+F11"
+            ),
+            286
+        );
+    }
+}
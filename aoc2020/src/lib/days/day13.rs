@@ -1,12 +1,8 @@
+use aoc_helpers::math::crt;
 use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -16,12 +12,12 @@ impl AocSolution for Solution {
         13
     }
 
-    fn part_one(&self, input: &str) -> String {
-        part_one(input).to_string()
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
     }
 
-    fn part_two(&self, input: &str) -> String {
-        part_two(input).to_string()
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
     }
 }
 
@@ -48,38 +44,19 @@ pub fn part_one(input: &str) -> u64 {
     min_id * min_diff
 }
 
-// The solution for IDs a and b is some number x in the range 0..lcm, where lcm
-// is the lowest common multiple of a and b.  Additional solutions can be found
-// using the formula lcm*i + x, where i is any integer.
-pub fn find_ts(offset: u64, id: u64, base: u64, prev_lcm: u64) -> u64 {
-    let mut maybe_ts = base;
-    loop {
-        if (maybe_ts + offset) % id == 0 {
-            return maybe_ts;
-        }
-        maybe_ts += prev_lcm;
-    }
-}
-
 pub fn part_two(input: &str) -> u64 {
     let mut lines = input.lines();
     lines.next();
     let ids = lines.next().unwrap().split(',');
-    let mut enumerated_ids: Vec<(u64, u64)> = Vec::new();
+    // The bus at `offset` leaves at t + offset, so t = -offset (mod id).
+    let mut departures: Vec<(i64, i64)> = Vec::new();
     for (offset, id) in ids.enumerate() {
         if id == "x" {
             continue;
         }
-        enumerated_ids.push((offset as u64, id.parse::<u64>().unwrap()));
-    }
-    enumerated_ids.sort_by(|a, b| a.1.cmp(&b.1));
-    let mut timestamp = 1;
-    let mut prev_lcm = 1;
-    for (o, id) in enumerated_ids {
-        timestamp = find_ts(o, id, timestamp, prev_lcm);
-        prev_lcm *= id;
+        departures.push((-(offset as i64), id.parse::<i64>().unwrap()));
     }
-    timestamp
+    crt(&departures).unwrap().0 as u64
 }
 
 #[cfg(test)]
@@ -3,12 +3,7 @@ use aoc_helpers::runner::*;
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -18,12 +13,12 @@ impl AocSolution for Solution {
         16
     }
 
-    fn part_one(&self, input: &str) -> String {
-        part_one(input).to_string()
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
     }
 
-    fn part_two(&self, input: &str) -> String {
-        part_two(input).to_string()
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
     }
 }
 
@@ -37,29 +32,15 @@ pub struct Rule {
 
 type Rules = HashMap<Rc<String>, Rule>;
 
-pub fn parse_rules<'a, I>(lines: &mut I) -> Rules
-where
-    I: Iterator<Item = &'a str>,
-{
+pub fn parse_rules(lines: &[&str]) -> Rules {
     let mut rules: Rules = HashMap::new();
-    while let Some(line) = lines.next() {
-        if line == "" {
-            return rules;
-        }
-        let mut parts = line.split(": ");
-        let key = Rc::new(parts.next().unwrap().to_owned());
-        let mut ranges = parts.next().unwrap().split(" or ");
-        let mut range1 = ranges.next().unwrap().split('-');
-        let mut range2 = ranges.next().unwrap().split('-');
+    for line in lines {
+        let (name, ranges) = line.split_once(": ").unwrap();
+        let key = Rc::new(name.to_owned());
+        let [a, b, c, d] = uints(ranges).array().unwrap();
         let rule = Rule {
-            range1: (
-                range1.next().unwrap().parse::<u64>().unwrap(),
-                range1.next().unwrap().parse::<u64>().unwrap(),
-            ),
-            range2: (
-                range2.next().unwrap().parse::<u64>().unwrap(),
-                range2.next().unwrap().parse::<u64>().unwrap(),
-            ),
+            range1: (a, b),
+            range2: (c, d),
             possible_indices: HashSet::new(),
             true_index: None,
         };
@@ -69,14 +50,25 @@ where
 }
 
 pub fn parse_ticket(line: &str) -> Vec<u64> {
-    line.split(',').map(|s| s.parse::<u64>().unwrap()).collect()
+    uints(line).collect::<Result<_, _>>().unwrap()
 }
 
-pub fn parse_their_ticket<'a, I>(lines: &mut I) -> Vec<Vec<u64>>
-where
-    I: Iterator<Item = &'a str>,
-{
-    lines.map(|s| parse_ticket(s)).collect()
+// The rules, your ticket and everyone else's tickets.
+pub fn parse_notes(input: &str) -> (Rules, Vec<u64>, Vec<Vec<u64>>) {
+    let mut rules = None;
+    let mut your_ticket = None;
+    let mut their_tickets = None;
+    for block in blocks(input) {
+        match block.header {
+            None => rules = Some(parse_rules(&block.lines)),
+            Some("your ticket") => your_ticket = Some(parse_ticket(block.lines[0])),
+            Some("nearby tickets") => {
+                their_tickets = Some(block.lines.iter().map(|s| parse_ticket(s)).collect())
+            }
+            Some(header) => panic!("unexpected section {:?}", header),
+        }
+    }
+    (rules.unwrap(), your_ticket.unwrap(), their_tickets.unwrap())
 }
 
 pub fn validate_ticket(ticket: &Vec<u64>, rules: &Rules) -> Option<u64> {
@@ -102,13 +94,7 @@ pub fn validate_ticket(ticket: &Vec<u64>, rules: &Rules) -> Option<u64> {
 }
 
 pub fn part_one(input: &str) -> u64 {
-    let mut lines = input.lines();
-    let rules = parse_rules(&mut lines);
-    lines.next(); // Consume the 'your ticket:' header
-    let _your_ticket = parse_ticket(lines.next().unwrap());
-    lines.next(); // Consume the blank line
-    lines.next(); // Consume the 'nearby tickets' header
-    let their_tickets = parse_their_ticket(&mut lines);
+    let (rules, _your_ticket, their_tickets) = parse_notes(input);
     their_tickets
         .iter()
         .map(|t| {
@@ -122,13 +108,7 @@ pub fn part_one(input: &str) -> u64 {
 }
 
 pub fn part_two(input: &str) -> u64 {
-    let mut lines = input.lines();
-    let mut rules = parse_rules(&mut lines);
-    lines.next(); // Consume the 'your ticket:' header
-    let your_ticket = parse_ticket(lines.next().unwrap());
-    lines.next(); // Consume the blank line
-    lines.next(); // Consume the 'nearby tickets' header
-    let their_tickets = parse_their_ticket(&mut lines);
+    let (mut rules, your_ticket, their_tickets) = parse_notes(input);
     let mut all_tickets: Vec<&Vec<u64>> = their_tickets
         .iter()
         .filter(|t| validate_ticket(t, &rules) == None)
@@ -0,0 +1,107 @@
+use aoc_helpers::automaton::{Neighborhood, SparseAutomaton};
+use aoc_helpers::parsing::*;
+use aoc_helpers::runner::*;
+use aoc_helpers::sparse::SparseGrid;
+
+pub struct Solution {}
+
+impl AocSolution for Solution {
+    fn year(&self) -> u32 {
+        2020
+    }
+    fn day(&self) -> u32 {
+        17
+    }
+
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
+    }
+}
+
+// Only the active cubes are stored.
+type Dimension<const N: usize> = SparseGrid<(), N>;
+
+// The input is a single x/y slice of the dimension; every other axis starts at 0.
+pub fn parse_input<const N: usize>(input: &str) -> Dimension<N> {
+    let mut dimension: Dimension<N> = Dimension::new();
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.char_indices() {
+            let mut point = [0; N];
+            point[0] = x as i64;
+            point[1] = y as i64;
+            match c {
+                '#' => {
+                    dimension.insert(point, ());
+                }
+                '.' => {}
+                _ => {
+                    panic!("unexpected input: {}", c);
+                }
+            }
+        }
+    }
+    dimension
+}
+
+pub fn cycle<const N: usize>(input: &str, cycles: usize) -> Dimension<N> {
+    let rule = |active: bool, neighbors: usize| neighbors == 3 || (active && neighbors == 2);
+    let mut automaton = SparseAutomaton::new(parse_input::<N>(input), Neighborhood::Moore, rule);
+    automaton.run(cycles);
+    automaton.live().clone()
+}
+
+pub fn part_one(input: &str) -> usize {
+    cycle::<3>(input, 6).len()
+}
+
+pub fn part_two(input: &str) -> usize {
+    cycle::<4>(input, 6).len()
+}
+
+#[cfg(test)]
+mod day17_tests {
+    use super::*;
+
+    #[test]
+    fn samples_part1() {
+        assert_eq!(
+            part_one(
+                ".#.
+..#
+###"
+            ),
+            112
+        );
+    }
+
+    #[test]
+    fn first_cycle() {
+        let dimension = cycle::<3>(".#.\n..#\n###", 1);
+        let draw = |c: Option<&()>| if c.is_some() { '#' } else { '.' };
+        // Rows are y and columns are x, as in the puzzle's pictures.
+        assert_eq!(
+            dimension.render_slice(1, 0, [0, 0, -1], draw),
+            "#..\n..#\n.#."
+        );
+        assert_eq!(
+            dimension.render_slice(1, 0, [0, 0, 0], draw),
+            "#.#\n.##\n.#."
+        );
+    }
+
+    #[test]
+    fn samples_part2() {
+        assert_eq!(
+            part_two(
+                ".#.
+..#
+###"
+            ),
+            848
+        );
+    }
+}
@@ -1,19 +1,16 @@
+use aoc_helpers::grid::Grid;
 use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
-use itertools::join;
 use num::traits::PrimInt;
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
+pub struct Solution {}
 
-struct Solution {}
+impl ParsedSolution for Solution {
+    type Parsed = BtoT;
 
-impl AocSolution for Solution {
     fn year(&self) -> u32 {
         2020
     }
@@ -21,24 +18,26 @@ impl AocSolution for Solution {
         20
     }
 
-    fn part_one(&self, input: &str) -> String {
-        part_one(input).to_string()
+    fn parse(&self, input: &str) -> Result<BtoT, ParseError> {
+        Ok(parse_input(input))
+    }
+
+    fn solve_part_one(&self, btot: &BtoT) -> Answer {
+        part_one(btot).into()
     }
 
-    fn part_two(&self, input: &str) -> String {
-        part_two(input).to_string()
+    fn solve_part_two(&self, btot: &BtoT) -> Answer {
+        part_two(btot).into()
     }
 }
 
 #[derive(Clone, Debug)]
 pub struct Tile {
     id: u64,
-    contents: String,
+    contents: Grid<char>,
     // Order: left, right, top, bottom,
     //        left_flipped, right_flipped, top_flipped, bottom_flipped.
     edges: [u128; 8],
-    width: usize,
-    height: usize,
 }
 
 impl FromStr for Tile {
@@ -78,7 +77,7 @@ impl FromStr for Tile {
             .sum();
         Ok(Tile {
             id: 0,
-            contents: tile_str.to_owned(),
+            contents: Grid::parse(tile_str, |c| c),
             edges: [
                 left,
                 right,
@@ -89,8 +88,6 @@ impl FromStr for Tile {
                 binary_flip(top, width),
                 binary_flip(bottom, width),
             ],
-            width,
-            height,
         })
     }
 }
@@ -98,7 +95,7 @@ impl FromStr for Tile {
 impl Tile {
     // Flip the tile vertically in place (along the horizontal axis).
     pub fn flip_v(&mut self) {
-        self.contents = join(self.contents.lines().rev(), "\n");
+        self.contents = self.contents.flip_vertical();
         self.edges = [
             self.edges[4],
             self.edges[5],
@@ -112,12 +109,7 @@ impl Tile {
     }
     // Flip the tile horizontally in place (along the vertical axis).
     pub fn flip_x(&mut self) {
-        self.contents = join(
-            self.contents
-                .lines()
-                .map(|l| l.chars().rev().collect::<String>()),
-            "\n",
-        );
+        self.contents = self.contents.flip_horizontal();
         self.edges = [
             self.edges[1],
             self.edges[0],
@@ -131,27 +123,7 @@ impl Tile {
     }
     // Rotate the tile 90 degrees clockwise in place.
     pub fn rot_right(&mut self) {
-        let mut new_contents = String::new();
-        for i in 0..self.height {
-            let new_row: String = self
-                .contents
-                .lines()
-                .fold(String::new(), |mut acc, s| {
-                    acc.push(s.chars().skip(i).next().unwrap());
-                    acc
-                })
-                .chars()
-                .rev()
-                .collect();
-            new_contents.push_str(&new_row);
-            if i != self.height - 1 {
-                new_contents.push('\n');
-            }
-        }
-        self.contents = new_contents;
-        let temp = self.height;
-        self.height = self.width;
-        self.width = temp;
+        self.contents = self.contents.rotate_right();
         self.edges = [
             self.edges[3],
             self.edges[2],
@@ -165,26 +137,7 @@ impl Tile {
     }
     // Rotate the tile 90 degrees counterclockwise in place.
     pub fn rot_left(&mut self) {
-        let mut new_contents = String::new();
-        for i in 0..self.height {
-            let new_row: String = self
-                .contents
-                .lines()
-                .fold(String::new(), |mut acc, s| {
-                    acc.push(s.chars().skip(self.height - i - 1).next().unwrap());
-                    acc
-                })
-                .chars()
-                .collect();
-            new_contents.push_str(&new_row);
-            if i != self.height - 1 {
-                new_contents.push('\n');
-            }
-        }
-        self.contents = new_contents;
-        let temp = self.height;
-        self.height = self.width;
-        self.width = temp;
+        self.contents = self.contents.rotate_left();
         self.edges = [
             self.edges[6],
             self.edges[7],
@@ -198,7 +151,7 @@ impl Tile {
     }
     // Rotate the tile 180 degrees in place.
     pub fn rot_180(&mut self) {
-        self.contents = self.contents.chars().rev().collect();
+        self.contents = self.contents.rotate_180();
         self.edges = [
             self.edges[5],
             self.edges[4],
@@ -287,28 +240,25 @@ impl Tile {
                 panic!("invalid side index {}", border);
             }
         }
-        // Update dimensions & edges.
-        self.width += source.width;
+        // Update edges.
+        let source_width = source.contents.width();
         self.edges[1] = source.edges[1];
-        self.edges[2] = (self.edges[2] << source.width) | source.edges[2];
-        self.edges[3] = (self.edges[3] << source.width) | source.edges[3];
+        self.edges[2] = (self.edges[2] << source_width) | source.edges[2];
+        self.edges[3] = (self.edges[3] << source_width) | source.edges[3];
         self.edges[5] = source.edges[5];
-        self.edges[6] = (source.edges[6] << source.width) | self.edges[6];
-        self.edges[7] = (source.edges[7] << source.width) | self.edges[7];
+        self.edges[6] = (source.edges[6] << source_width) | self.edges[6];
+        self.edges[7] = (source.edges[7] << source_width) | self.edges[7];
         // Stitch the content.
-        self.contents = join(
-            self.contents
-                .lines()
-                .zip(source.contents.lines())
-                .map(|(s1, s2)| format!("{}{}", s1, s2)),
-            "\n",
-        );
+        self.contents = Grid::stitch(&Grid::from_rows(vec![vec![
+            self.contents.clone(),
+            source.contents,
+        ]]));
     }
 }
 
 // A tile's border can be easily converted to a 10 bit number.  A BoT maps these
 // border-values with the IDs that have them.
-type BtoT = HashMap<u128, Vec<Tile>>;
+pub type BtoT = HashMap<u128, Vec<Tile>>;
 
 // Takes an unsigned integer |num| with |len| bits and reverses the bits.  Note that
 // |len| does not correspond to capacity of |num| in bits.  E.g. |num| may be a u16,
@@ -337,19 +287,15 @@ where
 // no two of these 8 values will ever be the same.
 pub fn parse_input(input: &str) -> BtoT {
     let mut ret = BtoT::new();
-    for tile_str in input.split("\n\n") {
-        let mut lines = tile_str.lines();
-        let id = lines
-            .next()
+    for block in blocks(input) {
+        let id = block
+            .header
             .unwrap()
             .strip_prefix("Tile ")
             .unwrap()
-            .strip_suffix(":")
-            .unwrap()
             .parse::<u64>()
             .unwrap();
-        let tile_body = join(lines, "\n");
-        let mut tile = tile_body.parse::<Tile>().unwrap();
+        let mut tile = block.lines.join("\n").parse::<Tile>().unwrap();
         tile.id = id;
         for border in tile.edges.iter() {
             ret.entry(*border).or_insert(Vec::new()).push(tile.clone());
@@ -358,13 +304,12 @@ pub fn parse_input(input: &str) -> BtoT {
     ret
 }
 
-pub fn part_one(input: &str) -> u64 {
-    let btot = parse_input(input);
+pub fn part_one(btot: &BtoT) -> u64 {
     // Assuming we can rely on the fact that any given border value will only
     // be used by two tiles (that are adjacent on that border), we just need to
     // find the 4 tiles that only pair with 2 other tiles.
     let mut per_tile_matches: HashMap<u64, usize> = HashMap::new();
-    for (_border, tiles) in btot {
+    for tiles in btot.values() {
         if tiles.len() == 2 {
             // This is a matching border, update each ID's count.
             for tile in tiles {
@@ -385,8 +330,8 @@ pub fn part_one(input: &str) -> u64 {
     product
 }
 
-pub fn part_two(input: &str) -> u64 {
-    let mut btot = parse_input(input);
+pub fn part_two(btot: &BtoT) -> u64 {
+    let mut btot = btot.clone();
     while btot.len() > 8 {
         // Iterate over all border matches in btot.  Stitch each matching pair, then put it into
         // next_btot to repeat in the next iteration.  Once btot only contains 8 entries, that
@@ -435,16 +380,17 @@ pub fn part_two(input: &str) -> u64 {
         btot = next_btot;
     }
     let final_tile = btot.values_mut().next().unwrap().pop().unwrap();
-    println!("{}", final_tile.contents);
+    let image = final_tile.contents.to_string();
+    println!("{}", image);
     let mut count = 0;
     let mut i = 0;
     let sm1 = Regex::new(r"..................#.").unwrap();
     let sm2 = Regex::new(r"#....##....##....###").unwrap();
     let sm3 = Regex::new(r".#..#..#..#..#..#...").unwrap();
-    while i < final_tile.contents.lines().count() - 2 {
-        let line1 = final_tile.contents.lines().nth(i).unwrap();
-        let line2 = final_tile.contents.lines().nth(i + 1).unwrap();
-        let line3 = final_tile.contents.lines().nth(i + 2).unwrap();
+    while i < image.lines().count() - 2 {
+        let line1 = image.lines().nth(i).unwrap();
+        let line2 = image.lines().nth(i + 1).unwrap();
+        let line3 = image.lines().nth(i + 2).unwrap();
         if let Some(m1) = sm1.find(line1) {
             if let Some(m2) = sm2.find(line2) {
                 if let Some(m3) = sm3.find(line3) {
@@ -467,7 +413,7 @@ mod day20_tests {
     #[test]
     fn samples_part1() {
         assert_eq!(
-            part_one(
+            part_one(&parse_input(
                 "Tile 2311:
 ..##.#..#.
 ##..#.....
@@ -575,14 +521,14 @@ Tile 3079:
 ..#.###...
 ..#.......
 ..#.###..."
-            ),
+            )),
             20899048083289
         );
     }
 
     #[test]
     fn samples_part2() {
-        assert_eq!(part_two(""), 0);
+        assert_eq!(part_two(&parse_input("")), 0);
     }
 
     #[test]
@@ -611,7 +557,7 @@ Tile 3079:
 
         tile.flip_v();
         assert_eq!(
-            tile.contents,
+            tile.contents.to_string(),
             "..####..##
 #..#....#.
 ###.....##
@@ -634,7 +580,7 @@ Tile 3079:
 
         tile.flip_x();
         assert_eq!(
-            tile.contents,
+            tile.contents.to_string(),
             "##..####..
 .#....#..#
 ##.....###
@@ -657,7 +603,7 @@ Tile 3079:
 
         tile.rot_right();
         assert_eq!(
-            tile.contents,
+            tile.contents.to_string(),
             "#.####.#.#
 ....#..###
 ..........
@@ -680,7 +626,7 @@ Tile 3079:
 
         tile.rot_left();
         assert_eq!(
-            tile.contents,
+            tile.contents.to_string(),
             "##..####..
 .#....#..#
 ##.....###
@@ -703,7 +649,7 @@ Tile 3079:
 
         tile.rot_180();
         assert_eq!(
-            tile.contents,
+            tile.contents.to_string(),
             ".##..#...#
 ##.#......
 .##......#
@@ -739,7 +685,7 @@ Tile 3079:
         .unwrap();
         tile.stitch(tile2, 757);
         assert_eq!(
-            tile.contents,
+            tile.contents.to_string(),
             ".##..#...###.#..###.
 ##.#.......##..###..
 .##......##.#...##.#
@@ -751,8 +697,8 @@ Tile 3079:
 #..#....#.....#..###
 ..####..###.##.#.#.#"
         );
-        assert_eq!(tile.width, 20);
-        assert_eq!(tile.height, 10);
+        assert_eq!(tile.contents.width(), 20);
+        assert_eq!(tile.contents.height(), 10);
         assert_eq!(tile.edges[0], 334);
         assert_eq!(tile.edges[1], 243);
         assert_eq!(tile.edges[2], 411470);
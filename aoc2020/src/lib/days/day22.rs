@@ -2,12 +2,7 @@ use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
 use std::collections::{HashSet, VecDeque};
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -17,12 +12,12 @@ impl AocSolution for Solution {
         22
     }
 
-    fn part_one(&self, input: &str) -> String {
-        part_one(input).to_string()
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
     }
 
-    fn part_two(&self, input: &str) -> String {
-        part_two(input).to_string()
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
     }
 }
 
@@ -31,22 +26,13 @@ type Deck = VecDeque<u32>;
 pub fn build_decks(input: &str) -> (Deck, Deck) {
     let mut p1: Deck = Deck::new();
     let mut p2: Deck = Deck::new();
-    let mut player = 0;
-    for line in input.lines() {
-        if line == "Player 1:" {
-            player = 1;
-            continue;
-        } else if line == "Player 2:" {
-            player = 2;
-            continue;
-        } else if line == "" {
-            continue;
-        }
-        if player == 1 {
-            p1.push_back(line.parse::<u32>().unwrap());
-        } else if player == 2 {
-            p2.push_back(line.parse::<u32>().unwrap());
-        }
+    for block in blocks(input) {
+        let deck = match block.header {
+            Some("Player 1") => &mut p1,
+            Some("Player 2") => &mut p2,
+            _ => panic!("expected a player's deck, found {:?}", block.header),
+        };
+        deck.extend(block.lines.iter().map(|line| line.parse::<u32>().unwrap()));
     }
     (p1, p2)
 }
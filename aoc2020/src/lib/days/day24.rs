@@ -0,0 +1,141 @@
+use aoc_helpers::automaton::{Neighborhood, SparseAutomaton};
+use aoc_helpers::parsing::*;
+use aoc_helpers::runner::*;
+use aoc_helpers::sparse::SparseGrid;
+
+pub struct Solution {}
+
+impl AocSolution for Solution {
+    fn year(&self) -> u32 {
+        2020
+    }
+    fn day(&self) -> u32 {
+        24
+    }
+
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
+    }
+}
+
+// The black tiles, in the axial hex coordinates used by the automaton module.
+type Tiles = SparseGrid<(), 2>;
+
+pub fn dir_to_delta(dir: &str) -> [i64; 2] {
+    match dir {
+        "e" => [0, 1],
+        "w" => [0, -1],
+        "ne" => [-1, 1],
+        "nw" => [-1, 0],
+        "se" => [1, 0],
+        "sw" => [1, -1],
+        _ => panic!("Invalid direction {}", dir),
+    }
+}
+
+// Follows the directions from the reference tile and flips the tile it ends on.
+pub fn walk_line(line: &str, tiles: &mut Tiles) {
+    let mut i = 0;
+    let mut tile = [0, 0];
+    while i < line.len() {
+        let len = if line[i..].starts_with(['e', 'w']) {
+            1
+        } else {
+            2
+        };
+        let delta = dir_to_delta(&line[i..(i + len)]);
+        tile = [tile[0] + delta[0], tile[1] + delta[1]];
+        i += len;
+    }
+    if tiles.remove(&tile).is_none() {
+        tiles.insert(tile, ());
+    }
+}
+
+pub fn flip_tiles(input: &str) -> Tiles {
+    let mut tiles = Tiles::new();
+    for line in input.lines() {
+        walk_line(line, &mut tiles);
+    }
+    tiles
+}
+
+pub fn part_one(input: &str) -> usize {
+    flip_tiles(input).len()
+}
+
+pub fn part_two(input: &str) -> usize {
+    // Black tiles stay black with one or two black neighbours, and white tiles
+    // turn black with exactly two.
+    let rule = |black: bool, neighbors: usize| neighbors == 2 || (black && neighbors == 1);
+    let mut floor = SparseAutomaton::new(flip_tiles(input), Neighborhood::Hex, rule);
+    floor.run(100);
+    floor.live().len()
+}
+
+#[cfg(test)]
+mod day24_tests {
+    use super::*;
+
+    #[test]
+    fn samples_part1() {
+        assert_eq!(
+            part_one(
+                "sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew"
+            ),
+            10
+        );
+    }
+
+    #[test]
+    fn samples_part2() {
+        assert_eq!(
+            part_two(
+                "sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew"
+            ),
+            2208
+        );
+    }
+}
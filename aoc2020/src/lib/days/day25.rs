@@ -0,0 +1,50 @@
+use aoc_helpers::math::{discrete_log, mod_pow};
+use aoc_helpers::parsing::*;
+use aoc_helpers::runner::*;
+
+pub struct Solution {}
+
+const MODULUS: i64 = 20201227;
+
+impl AocSolution for Solution {
+    fn year(&self) -> u32 {
+        2020
+    }
+    fn day(&self) -> u32 {
+        25
+    }
+
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(&self, _input: &str) -> Answer {
+        Answer::Unimplemented
+    }
+}
+
+// Each public key is 7^loop_size, so the card's loop size is a discrete log,
+// and the encryption key is the door's key raised to it.
+pub fn part_one(input: &str) -> i64 {
+    let mut lines = input.lines();
+    let card_pub = lines.next().unwrap().parse::<i64>().unwrap();
+    let door_pub = lines.next().unwrap().parse::<i64>().unwrap();
+    let card_loop = discrete_log(7, card_pub, MODULUS).unwrap();
+    mod_pow(door_pub, card_loop, MODULUS).unwrap()
+}
+
+#[cfg(test)]
+mod day25_tests {
+    use super::*;
+
+    #[test]
+    fn samples_part1() {
+        assert_eq!(part_one("5764801\n17807724"), 14897079);
+    }
+
+    #[test]
+    fn samples_part2() {
+        let solution = Solution {};
+        assert_eq!(solution.part_two(""), Answer::Unimplemented);
+    }
+}
@@ -2,12 +2,7 @@ use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
 use regex::Regex;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -17,12 +12,12 @@ impl AocSolution for Solution {
         4
     }
 
-    fn part_one(&self, input: &str) -> String {
-        part_one(input).to_string()
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
     }
 
-    fn part_two(&self, input: &str) -> String {
-        part_two(input).to_string()
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
     }
 }
 
@@ -122,22 +117,15 @@ fn validate_cid(_val: &str) -> bool {
 
 pub fn check_passports(input: &str, also_validate: bool) -> i64 {
     let mut count_valid = 0;
-    // Bit mask representing fields we've found.  Bits are in this order:
-    // byr, iyr, eyr, hgt, hcl, ecl, pid, cid.
-    let mut fields: u8 = 0;
-    for line in input.lines() {
-        // If we hit an empty line, we just finished a passport and gotta start a
-        // new one.
-        if line.is_empty() {
-            if fields == std::u8::MAX || fields == std::u8::MAX - 1 {
-                count_valid += 1;
-            }
-            fields = 0;
-            continue;
-        }
-
-        // We're starting a new passport.
-        for piece in line.split(' ') {
+    for passport in blocks(input) {
+        // Bit mask representing fields we've found.  Bits are in this order:
+        // byr, iyr, eyr, hgt, hcl, ecl, pid, cid.
+        let mut fields: u8 = 0;
+        for piece in passport
+            .lines
+            .iter()
+            .flat_map(|line| line.split_whitespace())
+        {
             let prefix = &piece[0..3];
             let val = &piece[4..];
             for pair in FIELD_PREFIXES.iter() {
@@ -148,11 +136,9 @@ pub fn check_passports(input: &str, also_validate: bool) -> i64 {
                 }
             }
         }
-    }
-
-    // We still need to check the final passport.
-    if fields == std::u8::MAX || fields == std::u8::MAX - 1 {
-        count_valid += 1;
+        if fields == u8::MAX || fields == u8::MAX - 1 {
+            count_valid += 1;
+        }
     }
     count_valid
 }
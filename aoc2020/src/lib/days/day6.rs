@@ -0,0 +1,65 @@
+use aoc_helpers::parsing::*;
+use aoc_helpers::runner::*;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+pub struct Solution {}
+
+impl AocSolution for Solution {
+    fn year(&self) -> u32 {
+        2020
+    }
+    fn day(&self) -> u32 {
+        6
+    }
+
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
+    }
+}
+
+pub fn part_one(input: &str) -> i64 {
+    let mut count = 0;
+    for group in blocks(input) {
+        let set: HashSet<char> = group.lines.iter().flat_map(|l| l.trim().chars()).collect();
+        count += set.len();
+    }
+    count as i64
+}
+
+pub fn part_two(input: &str) -> i64 {
+    let mut count = 0;
+    for group in blocks(input) {
+        let mut set: HashMap<char, usize> = HashMap::new();
+        for c in group.lines.iter().flat_map(|l| l.trim().chars()) {
+            let e = set.entry(c).or_insert(0);
+            *e += 1;
+        }
+        count += set.values().filter(|v| **v == group.lines.len()).count();
+    }
+    count as i64
+}
+
+#[cfg(test)]
+mod day6_tests {
+    use super::*;
+
+    #[test]
+    fn samples_part1() {
+        assert_eq!(part_one(""), 0);
+        assert_eq!(
+            part_one("abc\n\na\nb\nc\n\nab\nac\n\na\na\na\na\n\nb\n"),
+            11
+        );
+    }
+
+    #[test]
+    fn samples_part2() {
+        assert_eq!(part_two(""), 0);
+        assert_eq!(part_two("abc\n\na\nb\nc\n\nab\nac\n\na\na\na\na\n\nb\n"), 6);
+    }
+}
@@ -1,14 +1,9 @@
+use aoc_helpers::graph::Graph;
 use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
-use std::collections::HashMap;
-use std::collections::HashSet;
+use aoc_helpers::search::bfs;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -18,24 +13,23 @@ impl AocSolution for Solution {
         7
     }
 
-    fn part_one(&self, input: &str) -> String {
-        part_one(input).to_string()
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
     }
 
-    fn part_two(&self, input: &str) -> String {
-        part_two(input).to_string()
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
     }
 }
 
-type ColorMap = HashMap<String, HashSet<(String, u32)>>;
-
-pub fn build_color_map(input: &str) -> ColorMap {
-    let mut color_map: ColorMap = HashMap::new();
+// An edge from each bag color to every color it holds, weighted by how many.
+pub fn build_color_map(input: &str) -> Graph<u32> {
+    let mut color_map: Graph<u32> = Graph::new();
     for line in input.lines() {
         let mut pieces = line.split(" bags contain ");
         let color = pieces.next().unwrap();
+        color_map.intern(color);
         if line.contains("no other bags") {
-            color_map.insert(color.to_string(), HashSet::new());
             continue;
         }
         let rest = pieces
@@ -45,53 +39,36 @@ pub fn build_color_map(input: &str) -> ColorMap {
             .replace(" bag", "");
         let rest = rest.trim_end_matches('.');
         let rest = rest.trim();
-        let mut inner_colors: HashSet<(String, u32)> = HashSet::new();
         for count_color in rest.split(", ") {
             let mut pieces = count_color.splitn(2, ' ');
             let count = pieces.next().unwrap().parse::<u32>().unwrap();
-            let inner_c = pieces.next().unwrap().to_string();
-            inner_colors.insert((inner_c, count));
+            let inner_c = pieces.next().unwrap();
+            color_map.add_edge(color, inner_c, count);
         }
-        color_map.insert(color.to_string(), inner_colors);
     }
     color_map
 }
 
-pub fn find_color(start: &str, color: &str, color_map: &ColorMap) -> bool {
-    let maybe_set = color_map.get(start);
-    if maybe_set.is_none() {
-        return false;
-    }
-    let set = maybe_set.unwrap();
-    for (c, _count) in set {
-        if c == color {
-            return true;
-        }
-    }
-    for (c, _count) in set.iter() {
-        if find_color(c, color, color_map) {
-            return true;
-        }
-    }
-    false
-}
-
 pub fn part_one(input: &str) -> u32 {
     let color_map = build_color_map(input);
-
-    let mut count = 0;
-    for (k, _v) in &color_map {
-        if find_color(&k, "shiny gold", &color_map) {
-            count += 1;
-        }
-    }
-
-    count
+    let gold = match color_map.id("shiny gold") {
+        Some(gold) => gold,
+        None => return 0,
+    };
+
+    // Work outwards from the gold bag through the bags that hold each one.
+    let holders = bfs(gold, |&color| {
+        color_map
+            .incoming(color)
+            .map(|(outer, _)| outer)
+            .collect::<Vec<usize>>()
+    });
+    holders.len() as u32 - 1
 }
 
-pub fn get_contents(color: &str, color_map: &ColorMap) -> u32 {
+pub fn get_contents(color: usize, color_map: &Graph<u32>) -> u32 {
     let mut total = 0;
-    for (inner_color, count) in color_map.get(color).unwrap() {
+    for (inner_color, count) in color_map.neighbors(color) {
         total += (1 + get_contents(inner_color, color_map)) * count;
     }
     total
@@ -99,7 +76,11 @@ pub fn get_contents(color: &str, color_map: &ColorMap) -> u32 {
 
 pub fn part_two(input: &str) -> u32 {
     let color_map = build_color_map(input);
-    get_contents("shiny gold", &color_map)
+    // With no shiny gold bag there's nothing to fill it with.
+    match color_map.id("shiny gold") {
+        Some(gold) => get_contents(gold, &color_map),
+        None => 0,
+    }
 }
 
 #[cfg(test)]
@@ -122,6 +103,7 @@ dotted black bags contain no other bags."
             ),
             4
         );
+        assert_eq!(part_one(""), 0);
     }
 
     #[test]
@@ -140,5 +122,6 @@ dotted black bags contain no other bags."
             ),
             32
         );
+        assert_eq!(part_two(""), 0);
     }
 }
@@ -0,0 +1,138 @@
+use aoc_helpers::coord::Coord;
+use aoc_helpers::grid::Grid;
+use aoc_helpers::runner::*;
+use std::vec::Vec;
+
+pub struct Solution {}
+
+impl AocSolution for Solution {
+    fn year(&self) -> u32 {
+        2021
+    }
+    fn day(&self) -> u32 {
+        11
+    }
+
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
+    }
+}
+
+struct OctoGrid {
+    grid: Grid<u8>,
+    bright_bois: Vec<Coord>,
+    flashes: u64,
+}
+
+impl OctoGrid {
+    pub fn new(input: &str) -> OctoGrid {
+        OctoGrid {
+            grid: Grid::parse(input, |c| c.to_digit(10).unwrap() as u8),
+            bright_bois: Vec::new(),
+            flashes: 0,
+        }
+    }
+
+    fn increment(&mut self) {
+        for octo in self.grid.coords() {
+            self.grid[octo] += 1;
+            if self.grid[octo] > 9 {
+                self.bright_bois.push(octo);
+            }
+        }
+    }
+
+    fn flash(&mut self) {
+        let mut i = 0usize;
+        while i < self.bright_bois.len() {
+            let neighbors: Vec<Coord> = self.grid.neighbors8(self.bright_bois[i]).collect();
+            for neighbor in neighbors {
+                self.grid[neighbor] += 1;
+                if self.grid[neighbor] == 10 {
+                    self.bright_bois.push(neighbor);
+                }
+            }
+            i += 1;
+        }
+        while let Some(octo) = self.bright_bois.pop() {
+            self.grid[octo] = 0;
+            self.flashes += 1;
+        }
+    }
+
+    pub fn step(&mut self) {
+        self.increment();
+        self.flash();
+    }
+}
+
+pub fn part_one(input: &str) -> u64 {
+    let mut octos = OctoGrid::new(input);
+    for _i in 0..100 {
+        octos.step();
+    }
+    octos.flashes
+}
+
+pub fn part_two(input: &str) -> i64 {
+    let mut octos = OctoGrid::new(input);
+    let total_octos = (octos.grid.width() as u64) * (octos.grid.height() as u64);
+    let mut step = 1;
+    let mut flashes_before = 0;
+    loop {
+        octos.step();
+        if octos.flashes - flashes_before == total_octos {
+            break;
+        }
+        flashes_before = octos.flashes;
+        step += 1;
+    }
+    step
+}
+
+#[cfg(test)]
+mod day11_tests {
+    use super::*;
+
+    #[test]
+    fn samples_part1() {
+        assert_eq!(
+            part_one(
+                "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526"
+            ),
+            1656
+        );
+    }
+
+    #[test]
+    fn samples_part2() {
+        assert_eq!(
+            part_two(
+                "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526"
+            ),
+            195
+        );
+    }
+}
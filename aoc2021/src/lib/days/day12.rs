@@ -1,14 +1,10 @@
+use aoc_helpers::graph::Graph;
 use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 use std::vec::Vec;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -18,65 +14,67 @@ impl AocSolution for Solution {
         12
     }
 
-    fn part_one(&self, input: &str) -> String {
-        part_one(input).to_string()
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
     }
 
-    fn part_two(&self, input: &str) -> String {
-        part_two(input).to_string()
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
     }
 }
 
 struct Caves {
-    edges: HashMap<String, Vec<String>>,
-    paths: Vec<Vec<String>>,
+    graph: Graph<()>,
+    start: usize,
+    end: usize,
+    paths: Vec<Vec<usize>>,
     part_two: bool,
 }
 
 impl Caves {
     pub fn new(input: &str, part_two: bool) -> Caves {
-        let mut edges: HashMap<String, Vec<String>> = HashMap::new();
+        let mut graph: Graph<()> = Graph::new();
         for line in input.lines() {
             let mut edge = line.split('-');
             let cave1 = edge.next().unwrap();
             let cave2 = edge.next().unwrap();
-            edges
-                .entry(cave1.to_string())
-                .or_insert(Vec::new())
-                .push(cave2.to_string());
-            edges
-                .entry(cave2.to_string())
-                .or_insert(Vec::new())
-                .push(cave1.to_string());
+            graph.add_undirected(cave1, cave2, ());
         }
         Caves {
-            edges: edges,
+            start: graph.id("start").unwrap(),
+            end: graph.id("end").unwrap(),
+            graph,
             paths: Vec::new(),
             part_two: part_two,
         }
     }
 
-    fn is_small(cave: &str) -> bool {
-        cave.chars().next().unwrap().is_ascii_lowercase()
+    fn is_small(&self, cave: usize) -> bool {
+        self.graph
+            .name(cave)
+            .chars()
+            .next()
+            .unwrap()
+            .is_ascii_lowercase()
     }
 
-    fn is_cave_valid_for_path(&self, cave: &str, path: &Vec<String>) -> bool {
-        if !Caves::is_small(cave) {
+    fn is_cave_valid_for_path(&self, cave: usize, path: &Vec<usize>) -> bool {
+        if !self.is_small(cave) {
             return true;
         }
-        if cave == "start" {
+        if cave == self.start {
             return false;
         }
-        let mut visited: HashSet<String> = HashSet::new();
+        let mut visited: HashSet<usize> = HashSet::new();
         let mut repeats = 0;
         for c in path {
-            if !Caves::is_small(c) {
+            if !self.is_small(*c) {
                 continue;
             }
-            if !visited.insert(c.to_string()) {
+            if !visited.insert(*c) {
                 repeats += 1;
             }
-            if c == cave {
+            if *c == cave {
                 repeats += 1;
             }
             if (self.part_two && repeats > 1) || (!self.part_two && repeats > 0) {
@@ -87,18 +85,18 @@ impl Caves {
     }
 
     pub fn build_paths(&mut self) {
-        let mut potentials: Vec<Vec<String>> = Vec::new();
-        potentials.push(vec!["start".to_string()]);
+        let mut potentials: Vec<Vec<usize>> = Vec::new();
+        potentials.push(vec![self.start]);
         while !potentials.is_empty() {
             let base_path = potentials.pop().unwrap();
-            for cave in &self.edges[&base_path[base_path.len() - 1]] {
-                if cave == "end" {
+            for (cave, _) in self.graph.neighbors(base_path[base_path.len() - 1]) {
+                if cave == self.end {
                     let mut clone = base_path.clone();
-                    clone.push(cave.to_string());
+                    clone.push(cave);
                     self.paths.push(clone);
                 } else if self.is_cave_valid_for_path(cave, &base_path) {
                     let mut clone = base_path.clone();
-                    clone.push(cave.to_string());
+                    clone.push(cave);
                     potentials.push(clone);
                 }
             }
@@ -116,8 +114,8 @@ pub fn part_two(input: &str) -> usize {
     let mut caves = Caves::new(input, true);
     caves.build_paths();
     // for path in caves.paths {
-    //   let path_str = path.join(",");
-    //   println!("{}", path_str);
+    //   let path_str: Vec<&str> = path.iter().map(|c| caves.graph.name(*c)).collect();
+    //   println!("{}", path_str.join(","));
     // }
     caves.paths.len()
 }
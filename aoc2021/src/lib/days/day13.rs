@@ -3,12 +3,7 @@ use aoc_helpers::runner::*;
 use std::collections::HashSet;
 use std::vec::Vec;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -18,12 +13,12 @@ impl AocSolution for Solution {
         13
     }
 
-    fn part_one(&self, input: &str) -> String {
-        part_one(input).to_string()
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
     }
 
-    fn part_two(&self, input: &str) -> String {
-        part_two(input).to_string()
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
     }
 }
 
@@ -39,17 +34,15 @@ struct Page {
 
 impl Page {
     pub fn new(input: &str) -> Page {
-        let mut lines = input.lines();
-        let mut dotline = lines.next().unwrap().trim();
+        let sections = blocks(input);
         let mut dots: HashSet<(usize, usize)> = HashSet::new();
-        while dotline != "" {
-            let mut coords = dotline.split(",");
+        for dotline in &sections[0].lines {
+            let mut coords = dotline.trim().split(",");
             let (x, y) = (coords.next().unwrap(), coords.next().unwrap());
             dots.insert((x.parse::<usize>().unwrap(), y.parse::<usize>().unwrap()));
-            dotline = lines.next().unwrap().trim();
         }
         let mut folds: Vec<(Direction, usize)> = Vec::new();
-        for line in lines {
+        for line in &sections[1].lines {
             let replaced = line.replace("fold along ", "");
             let mut parts = replaced.split("=");
             let dir = parts.next().unwrap();
@@ -103,26 +96,21 @@ pub fn part_one(input: &str) -> usize {
     page.dots.len()
 }
 
-pub fn part_two(input: &str) -> usize {
+pub fn part_two(input: &str) -> String {
     let mut page = Page::new(input);
     page.fold_all();
-    let mut grid: Vec<Vec<char>> = Vec::new();
+    let width = page.dots.iter().map(|(x, _)| x + 1).max().unwrap_or(0);
+    let height = page.dots.iter().map(|(_, y)| y + 1).max().unwrap_or(0);
+    let mut grid = vec![vec!['.'; width]; height];
     for (x, y) in &page.dots {
-        while grid.len() <= *y {
-            grid.push(Vec::new());
-        }
-        while grid[*y].len() <= *x {
-            grid[*y].push('.');
-        }
         grid[*y][*x] = '#';
     }
+    let mut drawing = String::new();
     for row in grid {
-        for c in row {
-            print!("{}", c);
-        }
-        println!("");
+        drawing.extend(row);
+        drawing.push('\n');
     }
-    page.dots.len()
+    drawing
 }
 
 #[cfg(test)]
@@ -161,8 +149,9 @@ fold along x=5"
 
     #[test]
     fn samples_part2() {
+        let solution = Solution {};
         assert_eq!(
-            part_two(
+            solution.part_two(
                 "6,10
 0,14
 9,10
@@ -185,7 +174,11 @@ fold along x=5"
 fold along y=7
 fold along x=5"
             ),
-            16
+            "#####
+#...#
+#...#
+#...#
+#####"
         );
     }
 }
@@ -0,0 +1,139 @@
+use aoc_helpers::coord::Coord;
+use aoc_helpers::grid::Grid;
+use aoc_helpers::parsing::*;
+use aoc_helpers::runner::*;
+use aoc_helpers::search::astar;
+
+pub struct Solution {}
+
+impl ParsedSolution for Solution {
+    type Parsed = HeightMaps;
+
+    fn year(&self) -> u32 {
+        2021
+    }
+    fn day(&self) -> u32 {
+        15
+    }
+
+    fn parse(&self, input: &str) -> Result<HeightMaps, ParseError> {
+        HeightMaps::new(input)
+    }
+
+    fn solve_part_one(&self, maps: &HeightMaps) -> Answer {
+        part_one(maps).into()
+    }
+
+    fn solve_part_two(&self, maps: &HeightMaps) -> Answer {
+        part_two(maps).into()
+    }
+}
+
+#[derive(Clone)]
+pub struct HeightMaps {
+    map: Grid<u8>,
+}
+
+impl HeightMaps {
+    pub fn new(input: &str) -> Result<HeightMaps, ParseError> {
+        let map = Grid::try_parse(input, |c| c.to_digit(10).map(|d| d as u8))?;
+        Ok(HeightMaps { map })
+    }
+
+    // The full map is the original tiled 5x5, with each tile's risks one more
+    // than the tile above or to the left (9 wraps around to 1).
+    pub fn expand(&mut self) {
+        let (oldwidth, oldheight) = (self.map.width(), self.map.height());
+        self.map = Grid::from_fn(oldwidth * 5, oldheight * 5, |c| {
+            let (y, x) = (c.row as usize, c.col as usize);
+            let original = self.map[Coord::from((y % oldheight, x % oldwidth))];
+            let increase = (x / oldwidth) + (y / oldheight);
+            (original + increase as u8 - 1) % 9 + 1
+        });
+    }
+
+    // Heuristic guess at the shortest path from n to the end: take the
+    // manhattan distance between n and the end.
+    fn astar_h(&self, n: Coord) -> u32 {
+        n.manhattan(self.end()) as u32
+    }
+
+    fn end(&self) -> Coord {
+        Coord::from((self.map.height() - 1, self.map.width() - 1))
+    }
+
+    pub fn astar(&self) -> u32 {
+        let end = self.end();
+        let path = astar(
+            Coord::ORIGIN,
+            |&c| {
+                self.map
+                    .neighbors4(c)
+                    .map(|n| (n, self.map[n] as u32))
+                    .collect::<Vec<_>>()
+            },
+            |&c| self.astar_h(c),
+            |&c| c == end,
+        );
+        path.unwrap().cost
+    }
+}
+
+pub fn part_one(maps: &HeightMaps) -> u32 {
+    maps.astar()
+}
+
+pub fn part_two(maps: &HeightMaps) -> u32 {
+    let mut maps = maps.clone();
+    maps.expand();
+    maps.astar()
+}
+
+#[cfg(test)]
+mod day15_tests {
+    use super::*;
+
+    #[test]
+    fn samples_part1() {
+        assert_eq!(
+            part_one(
+                &HeightMaps::new(
+                    "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581"
+                )
+                .unwrap()
+            ),
+            40
+        );
+    }
+
+    #[test]
+    fn samples_part2() {
+        assert_eq!(
+            part_two(
+                &HeightMaps::new(
+                    "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581"
+                )
+                .unwrap()
+            ),
+            315
+        );
+    }
+}
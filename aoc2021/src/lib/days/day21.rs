@@ -0,0 +1,38 @@
+use aoc_helpers::parsing::*;
+use aoc_helpers::runner::*;
+
+pub struct Solution {}
+
+impl AocSolution for Solution {
+    fn year(&self) -> u32 {
+        2021
+    }
+    fn day(&self) -> u32 {
+        21
+    }
+
+    fn part_one(&self, _input: &str) -> Answer {
+        Answer::Unimplemented
+    }
+
+    fn part_two(&self, _input: &str) -> Answer {
+        Answer::Unimplemented
+    }
+}
+
+#[cfg(test)]
+mod day21_tests {
+    use super::*;
+
+    #[test]
+    fn samples_part1() {
+        let solution = Solution {};
+        assert_eq!(solution.part_one(""), Answer::Unimplemented);
+    }
+
+    #[test]
+    fn samples_part2() {
+        let solution = Solution {};
+        assert_eq!(solution.part_two(""), Answer::Unimplemented);
+    }
+}
@@ -1,14 +1,9 @@
 use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
-use regex::Regex;
-use std::collections::HashMap;
+use aoc_helpers::record::Format;
+use aoc_helpers::sparse::SparseGrid;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -18,25 +13,19 @@ impl AocSolution for Solution {
         5
     }
 
-    fn part_one(&self, input: &str) -> String {
-        part_one(input).to_string()
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
     }
 
-    fn part_two(&self, input: &str) -> String {
-        part_two(input).to_string()
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
     }
 }
 
 type MaybeRevIter<T> = Box<dyn DoubleEndedIterator<Item = T>>;
 
-fn parse_line(line: &str, re: &Regex) -> (i64, i64, i64, i64) {
-    let caps = re.captures(line).unwrap();
-    (
-        caps[1].parse::<i64>().unwrap(),
-        caps[2].parse::<i64>().unwrap(),
-        caps[3].parse::<i64>().unwrap(),
-        caps[4].parse::<i64>().unwrap(),
-    )
+fn parse_line(line: &str, format: &Format) -> (i64, i64, i64, i64) {
+    format.extract(line).unwrap()
 }
 
 fn get_iter(start: i64, end: i64) -> MaybeRevIter<i64> {
@@ -49,29 +38,29 @@ fn get_iter(start: i64, end: i64) -> MaybeRevIter<i64> {
 
 // Returns true if this point created a new collision (i.e. the point already
 // existed in the map with a quantity of 1).
-fn add_point_to_map(point: (i64, i64), map: &mut HashMap<(i64, i64), i64>) -> bool {
-    let entry = map.entry(point).or_insert(0);
+fn add_point_to_map(point: [i64; 2], map: &mut SparseGrid<i64, 2>) -> bool {
+    let entry = map.get_or_insert_with(point, || 0);
     *entry += 1;
     *entry == 2
 }
 
 fn solution(input: &str, include_diagonals: bool) -> i64 {
-    let re: Regex = Regex::new(r"^(\d+),(\d+) -> (\d+),(\d+)$").unwrap();
-    let mut map: HashMap<(i64, i64), i64> = HashMap::new();
+    let format = Format::new("{},{} -> {},{}");
+    let mut map: SparseGrid<i64, 2> = SparseGrid::new();
     let mut overlaps: i64 = 0;
     for line in input.lines() {
-        let (x1, y1, x2, y2) = parse_line(line, &re);
+        let (x1, y1, x2, y2) = parse_line(line, &format);
         if x1 == x2 {
             // Horizontal
             for y in get_iter(y1, y2) {
-                if add_point_to_map((x1, y), &mut map) {
+                if add_point_to_map([x1, y], &mut map) {
                     overlaps += 1;
                 }
             }
         } else if y1 == y2 {
             // Vertical
             for x in get_iter(x1, x2) {
-                if add_point_to_map((x, y1), &mut map) {
+                if add_point_to_map([x, y1], &mut map) {
                     overlaps += 1;
                 }
             }
@@ -85,7 +74,7 @@ fn solution(input: &str, include_diagonals: bool) -> i64 {
                 if maybex.is_none() || maybey.is_none() {
                     break;
                 }
-                if add_point_to_map((maybex.unwrap(), maybey.unwrap()), &mut map) {
+                if add_point_to_map([maybex.unwrap(), maybey.unwrap()], &mut map) {
                     overlaps += 1;
                 }
             }
@@ -0,0 +1,113 @@
+use aoc_helpers::coord::Coord;
+use aoc_helpers::grid::Grid;
+use aoc_helpers::runner::*;
+use aoc_helpers::search::flood_fill;
+use std::vec::Vec;
+
+pub struct Solution {}
+
+impl AocSolution for Solution {
+    fn year(&self) -> u32 {
+        2021
+    }
+    fn day(&self) -> u32 {
+        9
+    }
+
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
+    }
+}
+
+struct HeightMap {
+    grid: Grid<u8>,
+}
+
+impl HeightMap {
+    pub fn new(input: &str) -> HeightMap {
+        HeightMap {
+            grid: Grid::parse(input, |c| c as u8 - 48),
+        }
+    }
+
+    pub fn get_lowpoints(&self) -> Vec<Coord> {
+        self.grid
+            .iter()
+            .filter(|(point, val)| {
+                self.grid
+                    .neighbors4(*point)
+                    .all(|n| self.grid[n] > **val)
+            })
+            .map(|(point, _)| point)
+            .collect()
+    }
+
+    pub fn calc_risk(&self) -> u32 {
+        self.get_lowpoints()
+            .iter()
+            .fold(0u32, |acc, point| acc + 1 + self.grid[*point] as u32)
+    }
+
+    pub fn find_basin_size(&self, start: Coord) -> usize {
+        let bottom = self.grid[start];
+        flood_fill(&self.grid, start, |val| *val != 9 && *val >= bottom).len()
+    }
+}
+
+pub fn part_one(input: &str) -> u32 {
+    let map = HeightMap::new(input);
+    map.calc_risk()
+}
+
+pub fn part_two(input: &str) -> u64 {
+    let map = HeightMap::new(input);
+    let mut sizes: [usize; 3] = [0; 3];
+    for point in map.get_lowpoints() {
+        let mut size = map.find_basin_size(point);
+        for i in (0..3).rev() {
+            if size > sizes[i] {
+                let temp = sizes[i];
+                sizes[i] = size;
+                size = temp;
+            }
+        }
+    }
+    sizes.iter().fold(1u64, |acc, s| acc * *s as u64)
+}
+
+#[cfg(test)]
+mod day9_tests {
+    use super::*;
+
+    #[test]
+    fn samples_part1() {
+        assert_eq!(
+            part_one(
+                "2199943210
+3987894921
+9856789892
+8767896789
+9899965678"
+            ),
+            15
+        );
+    }
+
+    #[test]
+    fn samples_part2() {
+        assert_eq!(
+            part_two(
+                "2199943210
+3987894921
+9856789892
+8767896789
+9899965678"
+            ),
+            1134
+        );
+    }
+}
@@ -1,12 +1,7 @@
 use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -16,16 +11,16 @@ impl AocSolution for Solution {
         1
     }
 
-    fn part_one(&self, input: &str) -> String {
+    fn part_one(&self, input: &str) -> Answer {
         solution(input, false)
     }
 
-    fn part_two(&self, input: &str) -> String {
+    fn part_two(&self, input: &str) -> Answer {
         solution(input, true)
     }
 }
 
-fn solution(input: &str, inc_text: bool) -> String {
+fn solution(input: &str, inc_text: bool) -> Answer {
     let mut sum = 0u32;
     for line in input.lines() {
         let mut first_digit: Option<u8> = None;
@@ -40,7 +35,7 @@ fn solution(input: &str, inc_text: bool) -> String {
         }
         sum += ((first_digit.unwrap_or(0) as u32) * 10) + (second_digit.unwrap_or(0) as u32);
     }
-    sum.to_string()
+    sum.into()
 }
 
 #[cfg(test)]
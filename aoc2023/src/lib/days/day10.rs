@@ -0,0 +1,143 @@
+use aoc_helpers::coord::{Coord, Direction};
+use aoc_helpers::grid::Grid;
+use aoc_helpers::runner::*;
+use aoc_helpers::search::bfs;
+use std::collections::HashMap;
+
+pub struct Solution {}
+
+impl AocSolution for Solution {
+    fn year(&self) -> u32 {
+        2023
+    }
+    fn day(&self) -> u32 {
+        10
+    }
+
+    fn part_one(&self, input: &str) -> Answer {
+        let mut pipes = Grid::parse(input, |c| c);
+        let pipe_loop = trace_loop(&mut pipes);
+        (*pipe_loop.values().max().unwrap()).into()
+    }
+
+    fn part_two(&self, input: &str) -> Answer {
+        let mut pipes = Grid::parse(input, |c| c);
+        let pipe_loop = trace_loop(&mut pipes);
+
+        // Scanning along each row, every loop tile with a pipe going north
+        // crosses from outside to inside or back.  (A stretch like L-7 counts
+        // once and L-J twice, which is right since only the first goes across.)
+        let mut count = 0usize;
+        let mut inside = false;
+        for (pos, pipe) in pipes.iter() {
+            if pos.col == 0 {
+                inside = false;
+            }
+            if pipe_loop.contains_key(&pos) {
+                if openings(*pipe).contains(&Direction::North) {
+                    inside = !inside;
+                }
+            } else if inside {
+                count += 1;
+            }
+        }
+
+        count.into()
+    }
+}
+
+// Which ways a pipe leads out of its tile.
+fn openings(pipe: char) -> &'static [Direction] {
+    match pipe {
+        '|' => &[Direction::North, Direction::South],
+        '-' => &[Direction::East, Direction::West],
+        'L' => &[Direction::North, Direction::East],
+        'J' => &[Direction::North, Direction::West],
+        '7' => &[Direction::South, Direction::West],
+        'F' => &[Direction::South, Direction::East],
+        _ => &[],
+    }
+}
+
+// Replaces the S with whichever pipe joins up with its neighbours, then
+// follows the loop through it both ways at once.  Returns every tile on the
+// loop with how far it is from the start.
+fn trace_loop(pipes: &mut Grid<char>) -> HashMap<Coord, usize> {
+    let start = pipes.find(&'S').expect("No starting point");
+    let joined: Vec<Direction> = Direction::ALL
+        .into_iter()
+        .filter(|dir| {
+            pipes
+                .get(start.step(*dir))
+                .is_some_and(|pipe| openings(*pipe).contains(&dir.reverse()))
+        })
+        .collect();
+    pipes[start] = "|-LJ7F"
+        .chars()
+        .find(|pipe| {
+            let pipe_openings = openings(*pipe);
+            joined.len() == 2 && joined.iter().all(|dir| pipe_openings.contains(dir))
+        })
+        .expect("The start doesn't join up with exactly two pipes");
+
+    bfs(start, |pos| {
+        openings(pipes[*pos])
+            .iter()
+            .map(|dir| pos.step(*dir))
+            .collect::<Vec<_>>()
+    })
+}
+
+#[cfg(test)]
+mod day1_tests {
+    use super::*;
+
+    #[test]
+    fn samples_part1() {
+        let solution = Solution {};
+        assert_eq!(
+            solution.part_one(
+                "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF"
+            ),
+            "4"
+        );
+    }
+
+    #[test]
+    fn samples_part2() {
+        let solution = Solution {};
+        assert_eq!(
+            solution.part_two(
+                "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+.........."
+            ),
+            "4"
+        );
+        assert_eq!(
+            solution.part_two(
+                "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L"
+            ),
+            "10"
+        );
+    }
+}
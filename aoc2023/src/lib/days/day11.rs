@@ -1,14 +1,10 @@
 use std::collections::HashSet;
 
-use aoc_helpers::parsing::*;
+use aoc_helpers::coord::Coord;
+use aoc_helpers::grid::Grid;
 use aoc_helpers::runner::*;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -18,36 +14,36 @@ impl AocSolution for Solution {
         11
     }
 
-    fn part_one(&self, input: &str) -> String {
+    fn part_one(&self, input: &str) -> Answer {
         solve(input, 2)
     }
 
-    fn part_two(&self, input: &str) -> String {
+    fn part_two(&self, input: &str) -> Answer {
         solve(input, 1000000)
     }
 }
 
-fn solve(input: &str, expansion: u128) -> String {
-    let space = parse_matrix(input);
+fn solve(input: &str, expansion: u128) -> Answer {
+    let space = Grid::parse(input, |c| c);
 
-    let mut empty_rows: HashSet<usize> = HashSet::with_capacity(space.len());
-    for row in 0..space.len() {
+    let mut empty_rows: HashSet<usize> = HashSet::with_capacity(space.height());
+    for row in 0..space.height() {
         if is_row_empty(&space, row) {
             empty_rows.insert(row);
         }
     }
 
-    let mut empty_cols: HashSet<usize> = HashSet::with_capacity(space[0].len());
-    for col in 0..space[0].len() {
+    let mut empty_cols: HashSet<usize> = HashSet::with_capacity(space.width());
+    for col in 0..space.width() {
         if is_col_empty(&space, col) {
             empty_cols.insert(col);
         }
     }
 
     let mut galaxies: Vec<(usize, usize)> = Vec::new();
-    for row in 0..space.len() {
-        for col in 0..space[0].len() {
-            if space[row][col] == '#' {
+    for row in 0..space.height() {
+        for col in 0..space.width() {
+            if space[Coord::from((row, col))] == '#' {
                 galaxies.push((row, col));
             }
         }
@@ -74,13 +70,13 @@ fn solve(input: &str, expansion: u128) -> String {
             sum += distance;
         }
     }
-    sum.to_string()
+    sum.into()
 }
 
-fn is_col_empty(matrix: &Vec<Vec<char>>, col: usize) -> bool {
+fn is_col_empty(matrix: &Grid<char>, col: usize) -> bool {
     let mut is_empty = true;
-    for row in 0..matrix.len() {
-        if matrix[row][col] == '#' {
+    for row in 0..matrix.height() {
+        if matrix[Coord::from((row, col))] == '#' {
             is_empty = false;
             break;
         }
@@ -88,10 +84,10 @@ fn is_col_empty(matrix: &Vec<Vec<char>>, col: usize) -> bool {
     is_empty
 }
 
-fn is_row_empty(matrix: &Vec<Vec<char>>, row: usize) -> bool {
+fn is_row_empty(matrix: &Grid<char>, row: usize) -> bool {
     let mut is_empty = true;
-    for col in 0..matrix[0].len() {
-        if matrix[row][col] == '#' {
+    for col in 0..matrix.width() {
+        if matrix[Coord::from((row, col))] == '#' {
             is_empty = false;
             break;
         }
@@ -0,0 +1,124 @@
+use aoc_helpers::coord::Coord;
+use aoc_helpers::grid::Grid;
+use aoc_helpers::runner::*;
+use std::collections::HashMap;
+
+pub struct Solution {}
+
+impl AocSolution for Solution {
+    fn year(&self) -> u32 {
+        2023
+    }
+    fn day(&self) -> u32 {
+        14
+    }
+
+    fn part_one(&self, input: &str) -> Answer {
+        let mut grid = Grid::parse(input, |c| c);
+        tilt_north(&mut grid);
+        calc_load(&grid).into()
+    }
+
+    fn part_two(&self, input: &str) -> Answer {
+        let mut grid = Grid::parse(input, |c| c);
+
+        let mut memo: HashMap<Grid<char>, usize> = HashMap::new();
+        memo.insert(grid.clone(), 0);
+        let mut loop_start = 0;
+        let mut loop_size = 0;
+        for i in 0..1000000000 {
+            // Turning the grid clockwise after each tilt brings west, then
+            // south, then east round to the top.  Four turns put it back.
+            for _ in 0..4 {
+                tilt_north(&mut grid);
+                grid = grid.rotate_right();
+            }
+            if memo.contains_key(&grid) {
+                loop_start = *memo.get(&grid).unwrap();
+                loop_size = i - loop_start;
+                break;
+            }
+            memo.insert(grid.clone(), i + 1);
+        }
+
+        let end = ((1000000000 - loop_start) % (loop_size + 1)) + loop_start;
+        for (g, i) in memo.iter() {
+            if *i == end {
+                return calc_load(g).into();
+            }
+        }
+        calc_load(&grid).into()
+    }
+}
+
+fn calc_load(grid: &Grid<char>) -> usize {
+    let mut total = 0usize;
+    for col in 0..grid.width() {
+        for row in 0..grid.height() {
+            if grid[Coord::from((row, col))] == 'O' {
+                total += grid.height() - row;
+            }
+        }
+    }
+    total
+}
+
+fn tilt_north(grid: &mut Grid<char>) {
+    for col in 0..grid.width() {
+        let mut next = 0;
+        for row in 0..grid.height() {
+            if grid[Coord::from((row, col))] == 'O' {
+                grid[Coord::from((row, col))] = '.';
+                grid[Coord::from((next, col))] = 'O';
+                next += 1;
+            } else if grid[Coord::from((row, col))] == '#' {
+                next = row + 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod day1_tests {
+    use super::*;
+
+    #[test]
+    fn samples_part1() {
+        let solution = Solution {};
+        assert_eq!(
+            solution.part_one(
+                "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#...."
+            ),
+            "136"
+        );
+    }
+
+    #[test]
+    fn samples_part2() {
+        let solution = Solution {};
+        assert_eq!(
+            solution.part_two(
+                "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#...."
+            ),
+            "64"
+        );
+    }
+}
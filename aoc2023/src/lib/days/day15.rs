@@ -1,11 +1,6 @@
 use aoc_helpers::runner::*;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -15,16 +10,16 @@ impl AocSolution for Solution {
         15
     }
 
-    fn part_one(&self, input: &str) -> String {
+    fn part_one(&self, input: &str) -> Answer {
         let mut sum = 0;
         let chunks = input.split(",");
         for chunk in chunks {
             sum += run_hash(chunk);
         }
-        sum.to_string()
+        sum.into()
     }
 
-    fn part_two(&self, input: &str) -> String {
+    fn part_two(&self, input: &str) -> Answer {
         let mut boxes: Vec<Vec<(&str, usize)>> = Vec::with_capacity(256);
         for _ in 0..256 {
             boxes.push(Vec::new());
@@ -69,7 +64,7 @@ impl AocSolution for Solution {
                 sum += (1 + i) * (1 + j) * boxes[i][j].1;
             }
         }
-        sum.to_string()
+        sum.into()
     }
 }
 
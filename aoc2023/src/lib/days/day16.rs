@@ -0,0 +1,125 @@
+use std::collections::HashSet;
+
+use aoc_helpers::coord::{Coord, Direction};
+use aoc_helpers::grid::Grid;
+use aoc_helpers::runner::*;
+use aoc_helpers::search::bfs;
+
+pub struct Solution {}
+
+impl AocSolution for Solution {
+    fn year(&self) -> u32 {
+        2023
+    }
+    fn day(&self) -> u32 {
+        16
+    }
+
+    fn part_one(&self, input: &str) -> Answer {
+        let mirrors = Grid::parse(input, |c| c);
+        trace(&mirrors, (0, 0), Direction::East).into()
+    }
+
+    fn part_two(&self, input: &str) -> Answer {
+        let mirrors = Grid::parse(input, |c| c);
+        let (height, width) = (mirrors.height(), mirrors.width());
+
+        let mut starts: Vec<((usize, usize), Direction)> = Vec::new();
+        for col in 0..width {
+            starts.push(((0, col), Direction::South));
+            starts.push(((height - 1, col), Direction::North));
+        }
+        for row in 0..height {
+            starts.push(((row, 0), Direction::East));
+            starts.push(((row, width - 1), Direction::West));
+        }
+
+        starts
+            .into_iter()
+            .map(|(start, dir)| trace(&mirrors, start, dir))
+            .max()
+            .unwrap_or(0)
+            .into()
+    }
+}
+
+// Follows the beam (and every beam it splits into) from `start`, returning
+// how many tiles end up energized.
+fn trace(mirrors: &Grid<char>, start: (usize, usize), dir: Direction) -> usize {
+    let start = Coord::from(start);
+    if !mirrors.in_bounds(start) {
+        // Only possible on an empty grid.
+        return 0;
+    }
+    let beams = bfs((start, dir), |&(pos, dir)| {
+        let horizontal = dir == Direction::East || dir == Direction::West;
+        let next_dirs = match mirrors[pos] {
+            '/' if horizontal => vec![dir.turn_left()],
+            '/' => vec![dir.turn_right()],
+            '\\' if horizontal => vec![dir.turn_right()],
+            '\\' => vec![dir.turn_left()],
+            '|' if horizontal => vec![Direction::North, Direction::South],
+            '-' if !horizontal => vec![Direction::West, Direction::East],
+            _ => vec![dir],
+        };
+
+        next_dirs
+            .into_iter()
+            .map(|next_dir| (pos.step(next_dir), next_dir))
+            .filter(|(next, _)| mirrors.in_bounds(*next))
+            .collect::<Vec<_>>()
+    });
+
+    beams
+        .keys()
+        .map(|(pos, _)| *pos)
+        .collect::<HashSet<Coord>>()
+        .len()
+}
+
+#[cfg(test)]
+mod day1_tests {
+    use super::*;
+
+    #[test]
+    fn samples_part1() {
+        let solution = Solution {};
+        assert_eq!(
+            solution.part_one(
+                r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|...."
+            ),
+            "46"
+        );
+    }
+
+    #[test]
+    fn samples_part2() {
+        let solution = Solution {};
+        assert_eq!(
+            solution.part_two(
+                r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|...."
+            ),
+            "51"
+        );
+        assert_eq!(solution.part_two(""), "0");
+        assert_eq!(solution.part_one(""), "0");
+    }
+}
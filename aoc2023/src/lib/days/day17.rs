@@ -0,0 +1,127 @@
+use aoc_helpers::coord::{Coord, Direction};
+use aoc_helpers::grid::Grid;
+use aoc_helpers::runner::*;
+use aoc_helpers::search::dijkstra;
+
+pub struct Solution {}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    pos: Coord,
+    // None before the crucible has made its first move.
+    dir: Option<Direction>,
+    // How many blocks it's moved in a row in `dir`.
+    straight: u8,
+}
+
+impl AocSolution for Solution {
+    fn year(&self) -> u32 {
+        2023
+    }
+    fn day(&self) -> u32 {
+        17
+    }
+
+    fn part_one(&self, input: &str) -> Answer {
+        least_heat_loss(input, 1, 3).into()
+    }
+
+    fn part_two(&self, input: &str) -> Answer {
+        least_heat_loss(input, 4, 10).into()
+    }
+}
+
+// The crucible has to go at least `min` blocks in a straight line before it
+// can turn (or stop), and can't go more than `max`.  It never reverses.
+fn least_heat_loss(input: &str, min: u8, max: u8) -> u32 {
+    let city = Grid::parse(input, |c| c.to_digit(10).unwrap());
+    let end = Coord::from((city.height() - 1, city.width() - 1));
+    let start = State {
+        pos: Coord::ORIGIN,
+        dir: None,
+        straight: 0,
+    };
+    let moves = |state: &State| {
+        let mut next = Vec::new();
+        for dir in Direction::ALL {
+            let straight = match state.dir {
+                Some(d) if dir == d.reverse() => continue,
+                Some(d) if dir == d => state.straight + 1,
+                Some(_) if state.straight < min => continue,
+                _ => 1,
+            };
+            if straight > max {
+                continue;
+            }
+            let pos = state.pos + dir;
+            if let Some(&heat) = city.get(pos) {
+                let dir = Some(dir);
+                next.push((State { pos, dir, straight }, heat));
+            }
+        }
+        next
+    };
+    let is_goal = |state: &State| state.pos == end && state.straight >= min;
+    dijkstra(start, moves, is_goal).unwrap().cost
+}
+
+#[cfg(test)]
+mod day1_tests {
+    use super::*;
+
+    #[test]
+    fn samples_part1() {
+        let solution = Solution {};
+        assert_eq!(
+            solution.part_one(
+                "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533"
+            ),
+            "102"
+        );
+    }
+
+    #[test]
+    fn samples_part2() {
+        let solution = Solution {};
+        assert_eq!(
+            solution.part_two(
+                "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533"
+            ),
+            "94"
+        );
+        assert_eq!(
+            solution.part_two(
+                "111111111111
+999999999991
+999999999991
+999999999991
+999999999991"
+            ),
+            "71"
+        );
+    }
+}
@@ -1,12 +1,7 @@
 use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -16,7 +11,7 @@ impl AocSolution for Solution {
         2
     }
 
-    fn part_one(&self, input: &str) -> String {
+    fn part_one(&self, input: &str) -> Answer {
         let mut sum = 0u32;
         input.lines().for_each(|l| {
             let results = max_colors_for_game(l);
@@ -24,16 +19,16 @@ impl AocSolution for Solution {
                 sum += results.id;
             }
         });
-        sum.to_string()
+        sum.into()
     }
 
-    fn part_two(&self, input: &str) -> String {
+    fn part_two(&self, input: &str) -> Answer {
         let mut sum = 0u32;
         input.lines().for_each(|l| {
             let results = max_colors_for_game(l);
             sum += results.red * results.blue * results.green;
         });
-        sum.to_string()
+        sum.into()
     }
 }
 
@@ -1,13 +1,9 @@
-use aoc_helpers::parsing::*;
+use aoc_helpers::coord::Coord;
+use aoc_helpers::grid::Grid;
 use aoc_helpers::runner::*;
 use std::collections::{HashMap, HashSet};
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -17,12 +13,12 @@ impl AocSolution for Solution {
         3
     }
 
-    fn part_one(&self, input: &str) -> String {
-        part_one(input).to_string()
+    fn part_one(&self, input: &str) -> Answer {
+        part_one(input).into()
     }
 
-    fn part_two(&self, input: &str) -> String {
-        part_two(input).to_string()
+    fn part_two(&self, input: &str) -> Answer {
+        part_two(input).into()
     }
 }
 
@@ -36,19 +32,18 @@ enum SymbolResponse {
 enum AdjacencyResponse {
     None,
     Symbol,
-    Gears(Vec<(usize, usize)>),
+    Gears(Vec<Coord>),
 }
 
 pub fn part_one(input: &str) -> u32 {
-    let matrix = parse_matrix(input);
+    let matrix = Grid::parse(input, |c| c);
 
     let mut part_valid = false;
     let mut part_start = false;
     let mut part = 0u32;
     let mut sum = 0u32;
-    for row in 0..matrix.len() {
-        for col in 0..matrix[0].len() {
-            let c: char = matrix[row][col];
+    for (row, cells) in matrix.rows().enumerate() {
+        for (col, &c) in cells.iter().enumerate() {
             let is_digit = c.is_digit(10);
             if !part_start && is_digit {
                 // Start of a part.  Part value is initially the value of this digit.
@@ -57,7 +52,7 @@ pub fn part_one(input: &str) -> u32 {
                 part_valid = if part_valid {
                     true
                 } else {
-                    adjacent_to_symbol(row, col, &matrix) != AdjacencyResponse::None
+                    adjacent_to_symbol(Coord::from((row, col)), &matrix) != AdjacencyResponse::None
                 };
             } else if is_digit {
                 // In the middle of the part.  Multiply current part value by 10 and add the new digit.
@@ -66,7 +61,7 @@ pub fn part_one(input: &str) -> u32 {
                 part_valid = if part_valid {
                     true
                 } else {
-                    adjacent_to_symbol(row, col, &matrix) != AdjacencyResponse::None
+                    adjacent_to_symbol(Coord::from((row, col)), &matrix) != AdjacencyResponse::None
                 };
             } else if part_start {
                 // Just came out of a part.  If it was valid, add it to our sum. Then reset our state.
@@ -95,27 +90,25 @@ pub fn part_one(input: &str) -> u32 {
 }
 
 pub fn part_two(input: &str) -> u32 {
-    let matrix = parse_matrix(input);
-    // Each kv pair is (r_gear, c_gear) -> parts where:
-    // - r_gear is the row of the gear
-    // - c_gear is the column of the gear
+    let matrix = Grid::parse(input, |c| c);
+    // Each kv pair is gear -> parts where:
+    // - gear is the position of the gear
     // - parts is all the parts connected to this gear.
     // Note that the only valid gears are those that have exactly 2 parts in their list.
-    let mut gears: HashMap<(usize, usize), Vec<u32>> = HashMap::new();
+    let mut gears: HashMap<Coord, Vec<u32>> = HashMap::new();
 
-    let mut part_gears: HashSet<(usize, usize)> = HashSet::new();
+    let mut part_gears: HashSet<Coord> = HashSet::new();
     let mut part_start = false;
     let mut part = 0u32;
     let mut sum = 0u32;
-    for row in 0..matrix.len() {
-        for col in 0..matrix[0].len() {
-            let c: char = matrix[row][col];
+    for (row, cells) in matrix.rows().enumerate() {
+        for (col, &c) in cells.iter().enumerate() {
             let is_digit = c.is_digit(10);
             if !part_start && is_digit {
                 // Start of a part.  Part value is initially the value of this digit.
                 part = c.to_digit(10).expect("should've been a digit");
                 part_start = true;
-                match adjacent_to_symbol(row, col, &matrix) {
+                match adjacent_to_symbol(Coord::from((row, col)), &matrix) {
                     AdjacencyResponse::Gears(g) => {
                         part_gears.extend(g.into_iter());
                     }
@@ -125,7 +118,7 @@ pub fn part_two(input: &str) -> u32 {
                 // In the middle of the part.  Multiply current part value by 10 and add the new digit.
                 let digit = c.to_digit(10).expect("should've been a digit");
                 part = (part * 10) + digit;
-                match adjacent_to_symbol(row, col, &matrix) {
+                match adjacent_to_symbol(Coord::from((row, col)), &matrix) {
                     AdjacencyResponse::Gears(g) => {
                         part_gears.extend(g.into_iter());
                     }
@@ -177,123 +170,18 @@ fn is_symbol(c: char) -> SymbolResponse {
     }
 }
 
-fn adjacent_to_symbol(row: usize, col: usize, matrix: &Vec<Vec<char>>) -> AdjacencyResponse {
-    let mut gears: Vec<(usize, usize)> = Vec::new();
+fn adjacent_to_symbol(coord: Coord, matrix: &Grid<char>) -> AdjacencyResponse {
+    let mut gears: Vec<Coord> = Vec::new();
     let mut resp: AdjacencyResponse = AdjacencyResponse::None;
 
-    let top = row == 0;
-    let bottom = row == matrix.len() - 1;
-    let left = col == 0;
-    let right = col == matrix[0].len() - 1;
-    if !top && !left {
-        // top-left
-        let val = matrix[row - 1][col - 1];
-        let sym = is_symbol(val);
-        match sym {
-            SymbolResponse::None => (),
-            SymbolResponse::Symbol => {
-                resp = AdjacencyResponse::Symbol;
-            }
-            SymbolResponse::Gear => {
-                gears.push((row - 1, col - 1));
-            }
-        }
-    }
-    if !top {
-        // top
-        let val = matrix[row - 1][col];
-        let sym = is_symbol(val);
-        match sym {
-            SymbolResponse::None => (),
-            SymbolResponse::Symbol => {
-                resp = AdjacencyResponse::Symbol;
-            }
-            SymbolResponse::Gear => {
-                gears.push((row - 1, col));
-            }
-        }
-    }
-    if !top && !right {
-        // top-right
-        let val = matrix[row - 1][col + 1];
-        let sym = is_symbol(val);
-        match sym {
-            SymbolResponse::None => (),
-            SymbolResponse::Symbol => {
-                resp = AdjacencyResponse::Symbol;
-            }
-            SymbolResponse::Gear => {
-                gears.push((row - 1, col + 1));
-            }
-        }
-    }
-    if !left {
-        // left
-        let val = matrix[row][col - 1];
-        let sym = is_symbol(val);
-        match sym {
-            SymbolResponse::None => (),
-            SymbolResponse::Symbol => {
-                resp = AdjacencyResponse::Symbol;
-            }
-            SymbolResponse::Gear => {
-                gears.push((row, col - 1));
-            }
-        }
-    }
-    if !right {
-        // right
-        let val = matrix[row][col + 1];
-        let sym = is_symbol(val);
-        match sym {
-            SymbolResponse::None => (),
-            SymbolResponse::Symbol => {
-                resp = AdjacencyResponse::Symbol;
-            }
-            SymbolResponse::Gear => {
-                gears.push((row, col + 1));
-            }
-        }
-    }
-    if !bottom && !left {
-        // bottom-left
-        let val = matrix[row + 1][col - 1];
-        let sym = is_symbol(val);
-        match sym {
-            SymbolResponse::None => (),
-            SymbolResponse::Symbol => {
-                resp = AdjacencyResponse::Symbol;
-            }
-            SymbolResponse::Gear => {
-                gears.push((row + 1, col - 1));
-            }
-        }
-    }
-    if !bottom {
-        // bottom
-        let val = matrix[row + 1][col];
-        let sym = is_symbol(val);
-        match sym {
-            SymbolResponse::None => (),
-            SymbolResponse::Symbol => {
-                resp = AdjacencyResponse::Symbol;
-            }
-            SymbolResponse::Gear => {
-                gears.push((row + 1, col));
-            }
-        }
-    }
-    if !bottom && !right {
-        // bottom-right
-        let val = matrix[row + 1][col + 1];
-        let sym = is_symbol(val);
-        match sym {
+    for neighbor in matrix.neighbors8(coord) {
+        match is_symbol(matrix[neighbor]) {
             SymbolResponse::None => (),
             SymbolResponse::Symbol => {
                 resp = AdjacencyResponse::Symbol;
             }
             SymbolResponse::Gear => {
-                gears.push((row + 1, col + 1));
+                gears.push(neighbor);
             }
         }
     }
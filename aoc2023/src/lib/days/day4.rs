@@ -1,12 +1,7 @@
 use aoc_helpers::runner::*;
 use std::collections::HashSet;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -16,7 +11,7 @@ impl AocSolution for Solution {
         4
     }
 
-    fn part_one(&self, input: &str) -> String {
+    fn part_one(&self, input: &str) -> Answer {
         let mut sum = 0u32;
         for line in input.lines() {
             let matches = parse_card(line);
@@ -24,10 +19,10 @@ impl AocSolution for Solution {
                 sum += 2u32.pow(matches - 1);
             }
         }
-        sum.to_string()
+        sum.into()
     }
 
-    fn part_two(&self, input: &str) -> String {
+    fn part_two(&self, input: &str) -> Answer {
         let mut card_copies: Vec<u32> = Vec::with_capacity(203usize);
         card_copies.push(0);
         for (i, line) in input.lines().enumerate() {
@@ -46,7 +41,7 @@ impl AocSolution for Solution {
                 card_copies[j] += card_copies[i];
             }
         }
-        card_copies.iter().sum::<u32>().to_string()
+        card_copies.iter().sum::<u32>().into()
     }
 }
 
@@ -2,16 +2,19 @@ use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
 use std::cmp::{max, min};
 use std::collections::VecDeque;
-use std::str::Lines;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
+pub struct Solution {}
+
+// The seed numbers, followed by each map's (dest_start, src_start, length)
+// lines in order.
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Vec<(u64, u64, u64)>>,
 }
 
-struct Solution {}
+impl ParsedSolution for Solution {
+    type Parsed = Almanac;
 
-impl AocSolution for Solution {
     fn year(&self) -> u32 {
         2023
     }
@@ -19,31 +22,38 @@ impl AocSolution for Solution {
         5
     }
 
-    fn part_one(&self, input: &str) -> String {
-        let mut line_iter = input.lines();
-        let seed_line = line_iter.next().expect("Didn't get a seed line");
-        let mut seeds = get_seeds(seed_line);
-
-        line_iter.next(); // Throw away empty line
+    fn parse(&self, input: &str) -> Result<Almanac, ParseError> {
+        let sections = blocks(input);
+        let Some((first, maps)) = sections.split_first() else {
+            return Err(ParseError::at("", 0, "expected \"seeds:\"").on_line(1));
+        };
+        let seed_line = first.lines.first().copied().unwrap_or_default();
+        let seeds = get_seeds(seed_line).map_err(|e| e.on_line(first.line))?;
+        // Each map is a block of its own, under a "x-to-y map:" header.
+        let maps = maps
+            .iter()
+            .map(|map| map.parse_lines(parse_map_line))
+            .collect::<Result<_, _>>()?;
+        Ok(Almanac { seeds, maps })
+    }
 
-        for _i in 0..7 {
-            line_iter.next(); // Throw away map line
-            seeds = update_seed_values(&mut line_iter, seeds);
+    fn solve_part_one(&self, almanac: &Almanac) -> Answer {
+        let mut seeds: VecDeque<u64> = almanac.seeds.iter().copied().collect();
+        for map in &almanac.maps {
+            seeds = update_seed_values(map, seeds);
         }
 
-        seeds.into_iter().min().unwrap().to_string()
+        seeds.into_iter().min().unwrap().into()
     }
 
-    fn part_two(&self, input: &str) -> String {
-        let mut line_iter = input.lines();
-        let seed_line = line_iter.next().expect("Didn't get a seed line");
-        let mut ranges = get_seeds_as_ranges(seed_line);
-
-        line_iter.next(); // Throw away empty line
-
-        for _i in 0..7 {
-            line_iter.next(); // Throw away map line
-            ranges = update_ranges(&mut line_iter, ranges);
+    fn solve_part_two(&self, almanac: &Almanac) -> Answer {
+        let mut ranges: VecDeque<(u64, u64)> = almanac
+            .seeds
+            .chunks(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect();
+        for map in &almanac.maps {
+            ranges = update_ranges(map, ranges);
         }
 
         ranges
@@ -51,52 +61,20 @@ impl AocSolution for Solution {
             .min_by(|r1, r2| r1.0.cmp(&r2.0))
             .unwrap()
             .0
-            .to_string()
+            .into()
     }
 }
 
-fn get_seeds(line: &str) -> VecDeque<u64> {
-    let mut remainder: &str;
-
-    (remainder, _) = take_tag(line, "seeds:").expect("Failed to parse seed line");
-
-    let mut seeds: VecDeque<u64> = VecDeque::new();
-    while let Ok((rem, _)) = take_spaces(remainder) {
-        let (rem, num) = take_u64(rem).expect("Missing seed");
-        seeds.push_back(num);
-        remainder = rem
-    }
-    seeds
-}
-
-fn get_seeds_as_ranges(line: &str) -> VecDeque<(u64, u64)> {
-    let mut remainder: &str;
-
-    (remainder, _) = take_tag(line, "seeds:").expect("Failed to parse seed line");
-
-    let mut seeds: VecDeque<u64> = VecDeque::new();
-    while let Ok((rem, _)) = take_spaces(remainder) {
-        let (rem, num) = take_u64(rem).expect("Missing seed");
-        seeds.push_back(num);
-        remainder = rem
-    }
-
-    let mut ranges: VecDeque<(u64, u64)> = VecDeque::with_capacity(seeds.len() / 2);
-    for _i in 0..(seeds.len() / 2) {
-        let start = seeds.pop_front().unwrap();
-        let length = seeds.pop_front().unwrap();
-        ranges.push_back((start, length));
-    }
-    ranges
+fn get_seeds(line: &str) -> Result<Vec<u64>, ParseError> {
+    let Some(seeds) = line.strip_prefix("seeds:") else {
+        return Err(ParseError::at(line, 0, "expected \"seeds:\""));
+    };
+    parse_nums(seeds).map_err(|e| e.within(line, "seeds:".len()))
 }
 
-fn update_seed_values(lines: &mut Lines, mut old_vals: VecDeque<u64>) -> VecDeque<u64> {
+fn update_seed_values(map: &[(u64, u64, u64)], mut old_vals: VecDeque<u64>) -> VecDeque<u64> {
     let mut new_vals: VecDeque<u64> = VecDeque::with_capacity(old_vals.len());
-    while let Some(line) = lines.next() {
-        if line == "" {
-            break;
-        }
-        let (dest_start, src_start, length) = parse_map_line(line);
+    for &(dest_start, src_start, length) in map {
         for _i in 0..old_vals.len() {
             let v = old_vals.pop_front().unwrap();
             if v < src_start {
@@ -117,13 +95,12 @@ fn update_seed_values(lines: &mut Lines, mut old_vals: VecDeque<u64>) -> VecDequ
     new_vals
 }
 
-fn update_ranges(lines: &mut Lines, mut old_ranges: VecDeque<(u64, u64)>) -> VecDeque<(u64, u64)> {
+fn update_ranges(
+    map: &[(u64, u64, u64)],
+    mut old_ranges: VecDeque<(u64, u64)>,
+) -> VecDeque<(u64, u64)> {
     let mut new_ranges: VecDeque<(u64, u64)> = VecDeque::with_capacity(old_ranges.len());
-    while let Some(line) = lines.next() {
-        if line == "" {
-            break;
-        }
-        let (dest_start, src_start, length) = parse_map_line(line);
+    for &(dest_start, src_start, length) in map {
         for _i in 0..old_ranges.len() {
             let range = old_ranges.pop_front().unwrap();
             if range.0 + range.1 < src_start || src_start + length < range.0 {
@@ -155,19 +132,12 @@ fn update_ranges(lines: &mut Lines, mut old_ranges: VecDeque<(u64, u64)>) -> Vec
     new_ranges
 }
 
-fn parse_map_line(line: &str) -> (u64, u64, u64) {
-    let mut rem = line;
-    let mut result: (u64, u64, u64) = (0, 0, 0);
-
-    (rem, result.0) = take_u64(rem).expect("Missing dest_start");
-    (rem, _) = take_spaces(rem).expect("Missing space after dest_start");
-
-    (rem, result.1) = take_u64(rem).expect("Missing src_start");
-    (rem, _) = take_spaces(rem).expect("Missing space after src_start");
-
-    (_, result.2) = take_u64(rem).expect("Missing length");
-
-    result
+// dest_start, src_start, length
+fn parse_map_line(line: &str) -> Result<(u64, u64, u64), ParseError> {
+    match parse_nums(line)?[..] {
+        [dest_start, src_start, length] => Ok((dest_start, src_start, length)),
+        _ => Err(ParseError::at(line, 0, "expected three numbers")),
+    }
 }
 
 #[cfg(test)]
@@ -259,4 +229,26 @@ humidity-to-location map:
             "46"
         );
     }
+
+    #[test]
+    fn parse_errors() {
+        let solution = Solution {};
+        let err = solution.parse("seeds: 79 1x4 55\n").err().unwrap();
+        assert_eq!((err.line, err.column), (Some(1), 11));
+        let err = solution
+            .parse("seeds: 79\n\nseed-to-soil map:\n50 98 2\n52 50\n")
+            .err()
+            .unwrap();
+        assert_eq!(err.line, Some(5));
+        assert_eq!(err.message, "expected three numbers");
+        // Stray text or signs in a map line are errors, not just separators.
+        let err = solution
+            .parse("seeds: 79\n\nseed-to-soil map:\n50 x98 2\n")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (Some(4), 4));
+        assert!(solution
+            .parse("seeds: 79\n\nseed-to-soil map:\n-50 98 2\n")
+            .is_err());
+    }
 }
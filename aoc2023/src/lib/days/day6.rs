@@ -1,12 +1,7 @@
 use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -16,7 +11,7 @@ impl AocSolution for Solution {
         6
     }
 
-    fn part_one(&self, input: &str) -> String {
+    fn part_one(&self, input: &str) -> Answer {
         let mut iter = input.lines();
         let time_line = iter.next().unwrap();
         let dist_line = iter.next().unwrap();
@@ -47,10 +42,10 @@ impl AocSolution for Solution {
             product *= calc_combos(times[i] as f64, distances[i] as f64) as u64;
         }
 
-        product.to_string()
+        product.into()
     }
 
-    fn part_two(&self, input: &str) -> String {
+    fn part_two(&self, input: &str) -> Answer {
         let mut iter = input.lines();
         let time_line = iter.next().unwrap();
         let dist_line = iter.next().unwrap();
@@ -86,7 +81,7 @@ impl AocSolution for Solution {
 
         let result = calc_combos(time as f64, distance as f64) as u64;
 
-        result.to_string()
+        result.into()
     }
 }
 
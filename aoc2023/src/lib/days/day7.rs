@@ -3,12 +3,7 @@ use aoc_helpers::runner::*;
 use std::cmp::Ord;
 use std::cmp::Ordering;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -18,7 +13,7 @@ impl AocSolution for Solution {
         7
     }
 
-    fn part_one(&self, input: &str) -> String {
+    fn part_one(&self, input: &str) -> Answer {
         let mut hands: Vec<Hand> = Vec::new();
         for line in input.lines() {
             let hand = parse_hand(line);
@@ -29,10 +24,10 @@ impl AocSolution for Solution {
         for i in 0..hands.len() {
             sum += (i as u32 + 1u32) * hands[i].bid;
         }
-        sum.to_string()
+        sum.into()
     }
 
-    fn part_two(&self, input: &str) -> String {
+    fn part_two(&self, input: &str) -> Answer {
         let mut hands: Vec<Hand> = Vec::new();
         for line in input.lines() {
             let hand = parse_hand_with_jokers(line);
@@ -44,7 +39,7 @@ impl AocSolution for Solution {
             // println!("{:?}", hands[i]);
             sum += (i as u32 + 1u32) * hands[i].bid;
         }
-        sum.to_string()
+        sum.into()
     }
 }
 
@@ -1,15 +1,10 @@
-use aoc_helpers::math::lcm;
+use aoc_helpers::graph::Graph;
+use aoc_helpers::math::lcm_all;
 use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
-use std::collections::HashMap;
 use std::str::Lines;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -19,35 +14,26 @@ impl AocSolution for Solution {
         8
     }
 
-    fn part_one(&self, input: &str) -> String {
+    fn part_one(&self, input: &str) -> Answer {
         let mut iter = input.lines();
         let directions = parse_directions(iter.next().expect("Missing directions line"));
         iter.next(); // Skip blank line
 
         let node_map = parse_nodes(iter);
 
+        let end = node_map.id("ZZZ").expect("Uh oh, there's no ZZZ node");
         let mut steps = 0;
-        let mut node = "AAA";
+        let mut node = node_map.id("AAA").expect("Uh oh, there's no AAA node");
         let mut i = 0;
-        while let Some((left, right)) = node_map.get(node) {
-            if directions[i] == 'L' {
-                node = left;
-            } else {
-                node = right;
-            }
+        while node != end {
+            node = follow(&node_map, node, directions[i]);
             steps += 1;
             i += 1;
             if i >= directions.len() {
                 i = 0;
             }
-            if node == "ZZZ" {
-                break;
-            }
-        }
-        if node != "ZZZ" {
-            panic!("Uh oh, didn't find a path from AAA to ZZZ");
         }
-        steps.to_string()
+        steps.into()
     }
 
     // This solution relies on two assumptions that _are actually true_ for the input we're given:
@@ -60,17 +46,16 @@ impl AocSolution for Solution {
     // n steps leads back to the end node that it maps to.  So we can simply apply LCM sequentially
     // to all those loop sizes to calculate the minimum number of steps to reach our destination for
     // all starting points.
-    fn part_two(&self, input: &str) -> String {
+    fn part_two(&self, input: &str) -> Answer {
         let mut iter = input.lines();
         let directions = parse_directions(iter.next().expect("Missing directions line"));
         iter.next(); // Skip blank line
 
         let node_map = parse_nodes(iter);
 
-        let mut nodes: Vec<&str> = node_map
-            .keys()
-            .filter(|k| k.ends_with('A'))
-            .map(|k| k as &str)
+        let mut nodes: Vec<usize> = node_map
+            .nodes()
+            .filter(|n| node_map.name(*n).ends_with('A'))
             .collect();
         let mut loops: Vec<u64> = nodes.iter().map(|_| 0u64).collect();
 
@@ -83,14 +68,8 @@ impl AocSolution for Solution {
                 if loops[j] != 0 {
                     continue;
                 }
-                let node = nodes[j];
-                let (left, right) = node_map.get(node).expect("didn't find key node");
-                if directions[i] == 'L' {
-                    nodes[j] = left as &str;
-                } else {
-                    nodes[j] = right as &str;
-                }
-                if nodes[j].ends_with('Z') {
+                nodes[j] = follow(&node_map, nodes[j], directions[i]);
+                if node_map.name(nodes[j]).ends_with('Z') {
                     loops[j] = steps;
                     closed_loops += 1;
                 }
@@ -101,12 +80,7 @@ impl AocSolution for Solution {
             }
         }
 
-        steps = loops[0];
-        for l in loops.iter().skip(1) {
-            steps = lcm(steps, *l);
-        }
-
-        steps.to_string()
+        lcm_all(loops).expect("too many steps to count").into()
     }
 }
 
@@ -121,23 +95,33 @@ fn parse_directions(s: &str) -> Vec<char> {
     ret
 }
 
-fn parse_nodes(mut iter: Lines<'_>) -> HashMap<String, (String, String)> {
+// Each node gets an edge to its left and right neighbors, labelled 'L' and 'R'.
+fn parse_nodes(mut iter: Lines<'_>) -> Graph<char> {
     let mut rem: &str;
     let mut start: &str;
     let mut dest1: &str;
     let mut dest2: &str;
-    let mut node_map = HashMap::<String, (String, String)>::new();
+    let mut node_map = Graph::<char>::new();
     while let Some(line) = iter.next() {
         (rem, start) = take_n(line, 3).expect("Couldn't match on the start node");
         (rem, _) = take_tag(rem, " = (").expect("Couldn't trim the ' = (' tag");
         (rem, dest1) = take_n(rem, 3).expect("Couldn't match on dest1");
         (rem, _) = take_tag(rem, ", ").expect("Couldn't trim the ', ' tag");
         (_, dest2) = take_n(rem, 3).expect("Couldn't match on dest2");
-        node_map.insert(start.to_string(), (dest1.to_string(), dest2.to_string()));
+        node_map.add_edge(start, dest1, 'L');
+        node_map.add_edge(start, dest2, 'R');
     }
     node_map
 }
 
+fn follow(node_map: &Graph<char>, node: usize, direction: char) -> usize {
+    node_map
+        .neighbors(node)
+        .find(|(_, d)| **d == direction)
+        .map(|(next, _)| next)
+        .expect("didn't find key node")
+}
+
 #[cfg(test)]
 mod day1_tests {
     use super::*;
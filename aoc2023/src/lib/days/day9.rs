@@ -1,12 +1,7 @@
 use aoc_helpers::parsing::*;
 use aoc_helpers::runner::*;
 
-fn main() {
-    let solution = Solution {};
-    run(&solution);
-}
-
-struct Solution {}
+pub struct Solution {}
 
 impl AocSolution for Solution {
     fn year(&self) -> u32 {
@@ -16,7 +11,7 @@ impl AocSolution for Solution {
         9
     }
 
-    fn part_one(&self, input: &str) -> String {
+    fn part_one(&self, input: &str) -> Answer {
         let mut sum = 0i64;
         for line in input.lines() {
             let original_sequence = read_sequence(line);
@@ -29,10 +24,10 @@ impl AocSolution for Solution {
             }
             sum += new_val;
         }
-        sum.to_string()
+        sum.into()
     }
 
-    fn part_two(&self, input: &str) -> String {
+    fn part_two(&self, input: &str) -> Answer {
         let mut sum = 0i64;
         for line in input.lines() {
             let original_sequence = read_sequence(line);
@@ -51,26 +46,14 @@ impl AocSolution for Solution {
             }
             sum += new_val;
         }
-        sum.to_string()
+        sum.into()
     }
 }
 
 fn read_sequence(s: &str) -> Vec<i64> {
-    let mut rem = s;
-    let mut val: i64;
-    let mut vals: Vec<i64> = Vec::new();
-    while rem != "" {
-        (rem, val) = take_i64(rem).expect("Didn't get a number");
-        vals.push(val);
-        let result = take_spaces(rem);
-        if result.is_ok() {
-            rem = result.unwrap().0;
-        } else {
-            break;
-        }
-    }
-    vals
+    ints(s).collect::<Result<_, _>>().unwrap()
 }
+
 fn gen_next_sequence(seq: &Vec<i64>) -> (Vec<i64>, bool) {
     if seq.len() == 1 {
         return (vec![0], true);
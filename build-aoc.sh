@@ -1,4 +1,3 @@
 #!/bin/bash
 
-cd aoc${1}
-cargo build --bin day${2}
+cargo build -p aoc${1} -p aoc
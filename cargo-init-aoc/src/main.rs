@@ -55,8 +55,7 @@ fn main() {
 
     // Create the directory structure.
     println!("creating directory {}/", dir);
-    fs::create_dir_all(format!("{}/src/bin", dir)).expect("Failed to create bin directory");
-    fs::create_dir_all(format!("{}/src/lib", dir)).expect("Failed to create lib directory");
+    fs::create_dir_all(format!("{}/src/lib/days", dir)).expect("Failed to create days directory");
 
     // Create the cargo file.
     {
@@ -74,81 +73,6 @@ edition = \"2021\"
 name = \"aoc_{}_libs\"
 path = \"src/lib/lib.rs\"
 
-[[bin]]
-name = \"day1\"
-
-[[bin]]
-name = \"day2\"
-
-[[bin]]
-name = \"day3\"
-
-[[bin]]
-name = \"day4\"
-
-[[bin]]
-name = \"day5\"
-
-[[bin]]
-name = \"day6\"
-
-[[bin]]
-name = \"day7\"
-
-[[bin]]
-name = \"day8\"
-
-[[bin]]
-name = \"day9\"
-
-[[bin]]
-name = \"day10\"
-
-[[bin]]
-name = \"day11\"
-
-[[bin]]
-name = \"day12\"
-
-[[bin]]
-name = \"day13\"
-
-[[bin]]
-name = \"day14\"
-
-[[bin]]
-name = \"day15\"
-
-[[bin]]
-name = \"day16\"
-
-[[bin]]
-name = \"day17\"
-
-[[bin]]
-name = \"day18\"
-
-[[bin]]
-name = \"day19\"
-
-[[bin]]
-name = \"day20\"
-
-[[bin]]
-name = \"day21\"
-
-[[bin]]
-name = \"day22\"
-
-[[bin]]
-name = \"day23\"
-
-[[bin]]
-name = \"day24\"
-
-[[bin]]
-name = \"day25\"
-
 # See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
 
 [dependencies]
@@ -164,19 +88,14 @@ regex = \"1\"
 
     // Create the solution files.
     for i in 1..=25 {
-        let mut file = File::create(format!("{}/src/bin/day{}.rs", dir, i))
+        let mut file = File::create(format!("{}/src/lib/days/day{}.rs", dir, i))
             .expect("Failed to create aoc solution file");
         file.write_all(
             format!(
                 "use aoc_helpers::parsing::*;
-use aoc_helpers::runner::*;                
-
-fn main() {{
-    let solution = Solution {{}};
-    run(&solution);
-}}
+use aoc_helpers::runner::*;
 
-struct Solution {{}}
+pub struct Solution {{}}
 
 impl AocSolution for Solution {{
     fn year(&self) -> u32 {{
@@ -186,39 +105,94 @@ impl AocSolution for Solution {{
         {}
     }}
 
-    fn part_one(&self, _input: &str) -> String {{
-        \"\".to_string()
+    fn part_one(&self, _input: &str) -> Answer {{
+        Answer::Unimplemented
     }}
 
-    fn part_two(&self, _input: &str) -> String {{
-        \"\".to_string()
+    fn part_two(&self, _input: &str) -> Answer {{
+        Answer::Unimplemented
     }}
 }}
 
 #[cfg(test)]
-mod day1_tests {{
+mod day{}_tests {{
     use super::*;
 
     #[test]
     fn samples_part1() {{
         let solution = Solution {{}};
-        assert_eq!(solution.part_one(\"\"), \"\");
+        assert_eq!(solution.part_one(\"\"), Answer::Unimplemented);
     }}
 
     #[test]
     fn samples_part2() {{
         let solution = Solution {{}};
-        assert_eq!(solution.part_two(\"\"), \"\");
+        assert_eq!(solution.part_two(\"\"), Answer::Unimplemented);
     }}
 }}
 ",
-                year, i
+                year, i, i
             )
             .as_bytes(),
         )
         .expect("Failed to create aoc cargo file");
     }
 
-    // Create an empty lib file.
-    File::create(format!("{}/src/lib/lib.rs", dir)).expect("Failed to create aoc solution file");
+    // Create the lib files.  Days only get registered once they're solved.
+    {
+        let mut file =
+            File::create(format!("{}/src/lib/days.rs", dir)).expect("Failed to create days file");
+        for i in 1..=25 {
+            writeln!(file, "pub mod day{};", i).expect("Failed to write days file");
+        }
+        let mut file =
+            File::create(format!("{}/src/lib/lib.rs", dir)).expect("Failed to create lib file");
+        file.write_all(
+            format!(
+                "pub mod days;
+
+use aoc_helpers::registry::Registry;
+
+pub fn registry() -> Registry {{
+    Registry::new({})
+}}
+",
+                year
+            )
+            .as_bytes(),
+        )
+        .expect("Failed to create lib file");
+    }
+
+    // Hook the new year up to the aoc binary.
+    add_after(
+        "aoc/Cargo.toml",
+        &format!("{} = ", dir),
+        "aoc-helpers = ",
+        &format!("{} = {{ path = \"../{}\" }}", dir, dir),
+    );
+    add_after(
+        "aoc/src/main.rs",
+        &format!("aoc_{}_libs::registry()", year),
+        "_libs::registry(),",
+        &format!("        aoc_{}_libs::registry(),", year),
+    );
+}
+
+// Inserts `new_line` after the last line containing `anchor`, unless some line
+// already contains `existing`.
+fn add_after(path: &str, existing: &str, anchor: &str, new_line: &str) {
+    let contents = fs::read_to_string(path).expect("Couldn't read file");
+    if contents.contains(existing) {
+        println!("{} already references {}", path, existing);
+        return;
+    }
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let pos = lines
+        .iter()
+        .rposition(|l| l.contains(anchor))
+        .expect("Couldn't find where to add the new year");
+    lines.insert(pos + 1, new_line);
+    println!("Adding {} to {}", existing.trim_end_matches(" = "), path);
+    fs::write(path, lines.join("\n") + "\n").expect("Failed to update file");
 }
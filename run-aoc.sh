@@ -1,4 +1,3 @@
 #!/bin/bash
 
-cd aoc${1}
-cargo run --bin day${2}
\ No newline at end of file
+cargo run --bin aoc -- ${1} ${2} "${@:3}"
@@ -1,4 +1,3 @@
 #!/bin/bash
 
-cd aoc${1}
-cargo test --bin day${2}
\ No newline at end of file
+cargo test -p aoc${1} days::day${2}::
\ No newline at end of file